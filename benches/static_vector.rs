@@ -1,4 +1,6 @@
-use criterion::{Criterion, criterion_group, criterion_main};
+use core::hint::black_box;
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
 use static_vector::Vec;
 
 fn bench_static_vector(c: &mut Criterion) {
@@ -17,5 +19,60 @@ fn bench_static_vector(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_static_vector);
+fn bench_push(c: &mut Criterion) {
+    c.bench_function("push until full", |b| {
+        b.iter_batched_ref(
+            Vec::<u64, 4096>::new,
+            |vec| {
+                for i in 0..4096 {
+                    vec.push(black_box(i)).unwrap();
+                }
+            },
+            BatchSize::SmallInput,
+        );
+    });
+}
+
+fn bench_from_slice(c: &mut Criterion) {
+    let packet = [u8::MAX; 1500];
+    let mut group = c.benchmark_group("from slice");
+
+    group.bench_function("copy", |b| {
+        b.iter(|| Vec::<u8, 1500>::from_slice_copy(black_box(&packet)).unwrap());
+    });
+
+    group.bench_function("clone", |b| {
+        b.iter(|| {
+            let mut vec = Vec::<u8, 1500>::new();
+            vec.extend_from_slice(black_box(&packet)).unwrap();
+            vec
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_retain(c: &mut Criterion) {
+    let mut group = c.benchmark_group("retain");
+
+    for keep_percent in [0, 25, 50, 75, 100] {
+        group.bench_function(BenchmarkId::new("keep percent", keep_percent), |b| {
+            b.iter_batched_ref(
+                || {
+                    let mut vec = Vec::<i32, 1024>::new();
+                    for i in 0..1024 {
+                        vec.push(i).unwrap();
+                    }
+                    vec
+                },
+                |vec| vec.retain(|n| n.rem_euclid(100) < keep_percent),
+                BatchSize::SmallInput,
+            );
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_static_vector, bench_push, bench_from_slice, bench_retain);
 criterion_main!(benches);
@@ -23,15 +23,10 @@ impl<const N: usize> EventHistory<N> {
     fn insert(&mut self, event: Event) {
         if self.events.is_full() {
             // Remove the oldest event (FIFO)
-            let len = self.events.len();
-            self.events.as_mut_slice().copy_within(1..len, 0);
-
-            // Can ignore the error here since we are guaranteed to have at least one element
-            // because the vector is full.
-            let _ = self.events.pop();
+            self.events.shift_left(1);
         }
 
-        // Can ignore the error here since we are guaranteed to have space after popping
+        // Can ignore the error here since we are guaranteed to have space after truncating
         // if the vector was full.
         let _ = self.events.push(event);
     }
@@ -68,6 +68,7 @@ fuzz_target!(|data: &[u8]| {
 
     vec.as_mut_slice().fill(1);
     assert_eq!(vec.as_slice().iter().sum::<u8>(), vec.len() as u8);
+    assert_eq!(vec.wrapping_sum(), vec.len() as u8);
 
     vec.clear();
     for &byte in data {
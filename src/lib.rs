@@ -2,11 +2,20 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 #[macro_use]
 mod macros;
 
-use core::mem::MaybeUninit;
-use core::{cmp, error, fmt, slice};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec as AllocVec;
+use core::cell::Cell;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::mem::{self, ManuallyDrop, MaybeUninit};
+use core::ops::{Bound, ControlFlow, RangeBounds};
+use core::{array, cmp, error, fmt, ptr, slice};
 
 /// Error for when the vector is full or the requested operation would need more space than the
 /// capacity.
@@ -24,8 +33,90 @@ impl fmt::Display for CapacityError {
 
 impl error::Error for CapacityError {}
 
-#[derive(Debug)]
+/// Element types that [`Vec::const_eq()`] can compare in `const` contexts: primitive types whose
+/// values are equal exactly when their bytes are equal.
+///
+/// Implemented for integers, [`bool`] and [`char`]. It cannot be implemented outside this crate.
+pub trait ConstEq: Copy + sealed::Sealed {}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_const_eq {
+    ($($type:ty),+) => {
+        $(
+            impl sealed::Sealed for $type {}
+            impl ConstEq for $type {}
+        )+
+    };
+}
+
+impl_const_eq!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
+
+/// Element types whose vectors can be viewed as raw bytes by [`Vec::as_bytes_mut()`]: primitive
+/// numeric types that have no padding and for which any bit pattern is a valid value.
+///
+/// Implemented for integers, [`f32`] and [`f64`]. It cannot be implemented outside this crate.
+pub trait Pod: Copy + sealed::Sealed {}
+
+impl sealed::Sealed for f32 {}
+impl sealed::Sealed for f64 {}
+
+macro_rules! impl_pod {
+    ($($type:ty),+) => {
+        $(
+            impl Pod for $type {}
+        )+
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+/// Integer types that [`Vec::wrapping_sum()`] and [`Vec::wrapping_checksum()`] can add with
+/// wrapping arithmetic, so the result never panics on overflow.
+///
+/// Implemented for integers. It cannot be implemented outside this crate.
+pub trait WrappingAdd: Copy + sealed::Sealed {
+    /// The additive identity, returned for an empty vector.
+    const ZERO: Self;
+
+    /// Adds `rhs`, wrapping around at the boundary of the type.
+    #[must_use]
+    fn wrapping_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_wrapping_add {
+    ($($type:ty),+) => {
+        $(
+            impl WrappingAdd for $type {
+                const ZERO: Self = 0;
+
+                #[inline]
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$type>::wrapping_add(self, rhs)
+                }
+            }
+        )+
+    };
+}
+
+impl_wrapping_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
 /// A stack-allocated vector with fixed capacity and dynamic length.
+///
+/// The vector is [`Send`] and [`Sync`] exactly when `T` is, as the elements are stored inline.
+/// So a vector of `Rc` cannot be sent to another thread:
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+///
+/// use static_vector::Vec;
+///
+/// fn assert_send<T: Send>() {}
+///
+/// assert_send::<Vec<Rc<i32>, 4>>();
+/// ```
 pub struct Vec<T, const CAPACITY: usize> {
     data: [MaybeUninit<T>; CAPACITY],
     length: usize,
@@ -111,6 +202,25 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         self.length
     }
 
+    /// Returns the number of bytes occupied by the elements in the vector, which is the length
+    /// multiplied by the size of `T`. It is zero for zero-sized types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1_u32, 2, 3];
+    ///
+    /// assert_eq!(vec.byte_len(), 12);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("size_of_contents", "size_in_bytes"))]
+    pub const fn byte_len(&self) -> usize {
+        self.length * size_of::<T>()
+    }
+
     /// Returns whether the vector has no elements.
     ///
     /// # Example
@@ -149,6 +259,40 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         self.len() == self.capacity()
     }
 
+    /// Checks whether `additional` more elements fit in the vector, so a sequence of insertions
+    /// can be validated with `?` before any of them is performed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the current length plus `additional` exceeds the capacity.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{CapacityError, Vec};
+    ///
+    /// fn write_header(frame: &mut Vec<u8, 4>) -> Result<(), CapacityError> {
+    ///     frame.ensure_capacity(3)?;
+    ///
+    ///     frame.push(0x7E)?;
+    ///     frame.push(0x01)?;
+    ///     frame.push(0x02)?;
+    ///     Ok(())
+    /// }
+    ///
+    /// let mut frame = Vec::<u8, 4>::new();
+    /// write_header(&mut frame).unwrap();
+    /// assert_eq!(frame.as_slice(), [0x7E, 0x01, 0x02]);
+    ///
+    /// write_header(&mut frame).unwrap_err();
+    /// assert_eq!(frame.len(), 3);
+    /// ```
+    #[inline]
+    #[doc(alias("reserve", "reserve_exact", "has_room_for"))]
+    pub const fn ensure_capacity(&self, additional: usize) -> Result<(), CapacityError> {
+        if additional <= CAPACITY - self.length { Ok(()) } else { Err(CapacityError) }
+    }
+
     /// Adds the given `value` to the end of the vector.
     ///
     /// # Errors
@@ -193,11 +337,15 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
     #[inline]
     #[doc(alias("add", "append", "insert"))]
     pub fn push(&mut self, value: T) -> Result<(), CapacityError> {
-        if self.is_full() {
-            Err(CapacityError)
-        } else {
-            self.push_unchecked(value);
+        // The length is loaded once, and the slot lookup is the only capacity check, so the
+        // common path is a single comparison followed by a store.
+        let length = self.length;
+        if let Some(slot) = self.data.get_mut(length) {
+            slot.write(value);
+            self.length = length + 1;
             Ok(())
+        } else {
+            Err(CapacityError)
         }
     }
 
@@ -221,6 +369,30 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         self.length = 0;
     }
 
+    /// Moves all elements into a new vector with the same capacity, which is returned, leaving
+    /// this vector empty. Equivalent to [`mem::take()`], without cloning or dropping any element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut pending = vec![5; 1, 2, 3];
+    ///
+    /// let batch = pending.take();
+    /// assert_eq!(batch.as_slice(), [1, 2, 3]);
+    /// assert!(pending.is_empty());
+    ///
+    /// pending.push(4).unwrap();
+    /// assert_eq!(pending.as_slice(), [4]);
+    /// ```
+    #[inline]
+    #[must_use = "use clear() to drop the elements"]
+    #[doc(alias("replace", "swap", "clear"))]
+    pub fn take(&mut self) -> Self {
+        mem::take(self)
+    }
+
     /// Resizes the vector to the `new_length`.
     ///
     /// # Requirements
@@ -285,6 +457,52 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         Ok(())
     }
 
+    /// Sets the length of the vector to `new_length` without dropping elements when shrinking and
+    /// without initializing elements when growing. Only the capacity is checked.
+    ///
+    /// Elements beyond `new_length` are not dropped when shrinking, so they are leaked unless they
+    /// are made part of the vector again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if `new_length` exceeds the vector's fixed capacity. In this case,
+    /// the length is not changed.
+    ///
+    /// # Safety
+    ///
+    /// When growing, the elements in `len()..new_length` become part of the vector without being
+    /// initialized by this method. They must have been initialized before, for example by
+    /// shrinking the vector with this method, which leaves the removed elements in place.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    ///
+    /// // SAFETY: Shrinking never exposes uninitialized elements.
+    /// unsafe { vec.checked_set_len(1) }.unwrap();
+    /// assert_eq!(vec.as_slice(), [1]);
+    ///
+    /// // SAFETY: The elements at indexes 1 and 2 were left initialized when shrinking.
+    /// unsafe { vec.checked_set_len(3) }.unwrap();
+    /// assert_eq!(vec.as_slice(), [1, 2, 3]);
+    ///
+    /// // SAFETY: The capacity is exceeded, so the length is not changed.
+    /// assert!(unsafe { vec.checked_set_len(4) }.is_err());
+    /// ```
+    #[inline]
+    #[doc(alias("set_len_unchecked", "force_len", "length"))]
+    pub const unsafe fn checked_set_len(&mut self, new_length: usize) -> Result<(), CapacityError> {
+        if new_length > CAPACITY {
+            return Err(CapacityError);
+        }
+
+        self.length = new_length;
+        Ok(())
+    }
+
     /// Returns a reference to the first element in the vector, or [`None`] if the vector is empty.
     ///
     /// # Example
@@ -375,6 +593,44 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         if self.is_empty() { None } else { self.get_mut(self.len() - 1) }
     }
 
+    /// Returns a reference to the front element in the vector, or [`None`] if the vector is empty.
+    /// Same as [`Vec::first()`], named for queue-style usage.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let queue = vec![1, 2, 3];
+    ///
+    /// assert_eq!(queue.peek(), Some(&1));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("front", "first"))]
+    pub const fn peek(&self) -> Option<&T> {
+        self.first()
+    }
+
+    /// Returns a reference to the back element in the vector, or [`None`] if the vector is empty.
+    /// Same as [`Vec::last()`], named for queue-style usage.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let queue = vec![1, 2, 3];
+    ///
+    /// assert_eq!(queue.peek_back(), Some(&3));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("back", "last"))]
+    pub const fn peek_back(&self) -> Option<&T> {
+        self.last()
+    }
+
     /// Returns a reference to the element at the specified `index`, or [`None`] if out of bounds.
     ///
     /// # Example
@@ -435,6 +691,110 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         }
     }
 
+    /// Returns a reference to the element at `index` modulo the length, or [`None`] if the vector
+    /// is empty. Indexes past the end wrap around to the beginning of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let frames = vec!['a', 'b', 'c'];
+    ///
+    /// assert_eq!(frames.get_wrapping(1), Some(&'b'));
+    /// assert_eq!(frames.get_wrapping(3), Some(&'a'));
+    /// assert_eq!(frames.get_wrapping(7), Some(&'b'));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("cycle", "circular", "modulo"))]
+    pub const fn get_wrapping(&self, index: usize) -> Option<&T> {
+        match index.checked_rem(self.length) {
+            Some(wrapped) => self.get(wrapped),
+            None => None,
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index` modulo the length, or [`None`] if the
+    /// vector is empty. Indexes past the end wrap around to the beginning of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut frames = vec!['a', 'b', 'c'];
+    ///
+    /// if let Some(frame) = frames.get_wrapping_mut(4) {
+    ///     *frame = 'x';
+    /// }
+    /// assert_eq!(frames.as_slice(), ['a', 'x', 'c']);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("cycle", "circular", "modulo"))]
+    pub const fn get_wrapping_mut(&mut self, index: usize) -> Option<&mut T> {
+        match index.checked_rem(self.length) {
+            Some(wrapped) => self.get_mut(wrapped),
+            None => None,
+        }
+    }
+
+    /// Returns a reference to the element at the specified `index`, or the length of the vector
+    /// as the error if out of bounds, so the valid bound can be reported without another call.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current length if `index` is not less than it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![10, 20, 30];
+    ///
+    /// assert_eq!(vec.try_get(1), Ok(&20));
+    /// assert_eq!(vec.try_get(7), Err(3));
+    /// ```
+    #[inline]
+    #[doc(alias("at", "index", "checked_index"))]
+    pub const fn try_get(&self, index: usize) -> Result<&T, usize> {
+        match self.get(index) {
+            Some(element) => Ok(element),
+            None => Err(self.length),
+        }
+    }
+
+    /// Returns a mutable reference to the element at the specified `index`, or the length of the
+    /// vector as the error if out of bounds.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current length if `index` is not less than it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![10, 20, 30];
+    ///
+    /// *vec.try_get_mut(1).unwrap() = 25;
+    /// assert_eq!(vec.as_slice(), [10, 25, 30]);
+    ///
+    /// assert_eq!(vec.try_get_mut(3), Err(3));
+    /// ```
+    #[inline]
+    #[doc(alias("at", "index", "checked_index_mut"))]
+    pub const fn try_get_mut(&mut self, index: usize) -> Result<&mut T, usize> {
+        let length = self.length;
+        match self.get_mut(index) {
+            Some(element) => Ok(element),
+            None => Err(length),
+        }
+    }
+
     /// Returns (and removes) the last element from the vector, or [`None`] if the vector is empty.
     ///
     /// # Example
@@ -565,8 +925,11 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
     #[must_use]
     #[inline]
     pub const fn as_slice(&self) -> &[T] {
-        // SAFETY: A correct length is used to avoid accessing uninitialized elements.
-        unsafe { slice::from_raw_parts(self.data[0].as_ptr(), self.len()) }
+        // SAFETY:
+        // - A correct length is used to avoid accessing uninitialized elements.
+        // - The pointer is derived from the whole storage, not from an element, so it is valid for
+        //   zero-sized types too, for which no memory is read.
+        unsafe { slice::from_raw_parts(self.data.as_ptr().cast::<T>(), self.len()) }
     }
 
     /// Returns a mutable slice of the entire vector.
@@ -587,8 +950,39 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
     #[must_use]
     #[inline]
     pub const fn as_mut_slice(&mut self) -> &mut [T] {
-        // SAFETY: A correct length is used to avoid accessing uninitialized elements.
-        unsafe { slice::from_raw_parts_mut(self.data[0].as_mut_ptr(), self.len()) }
+        // SAFETY:
+        // - A correct length is used to avoid accessing uninitialized elements.
+        // - The pointer is derived from the whole storage, not from an element, so it is valid for
+        //   zero-sized types too, for which no memory is read.
+        unsafe { slice::from_raw_parts_mut(self.data.as_mut_ptr().cast::<T>(), self.len()) }
+    }
+
+    /// Returns the whole backing storage of the vector, `CAPACITY` slots long: the first
+    /// [`Vec::len()`] slots hold the elements, the others are the uninitialized spare capacity.
+    ///
+    /// Intended for low-level use, such as diagnostics. Calling [`MaybeUninit::assume_init_ref()`]
+    /// or similar on a slot at or after [`Vec::len()`] is undefined behavior, as its content may be
+    /// uninitialized or may belong to an element that was already dropped or moved out.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![4; 1, 2];
+    ///
+    /// let storage = vec.as_uninit_slice();
+    /// assert_eq!(storage.len(), 4);
+    ///
+    /// // SAFETY: The first `len()` slots are initialized.
+    /// let first = unsafe { storage[0].assume_init_ref() };
+    /// assert_eq!(*first, 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("storage", "raw", "spare_capacity"))]
+    pub const fn as_uninit_slice(&self) -> &[MaybeUninit<T>] {
+        &self.data
     }
 
     /// Inserts elements of given slice at the end of the vector.
@@ -646,6 +1040,77 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         Ok(())
     }
 
+    /// Clones as many elements from the beginning of `slice` as fit into the vector and appends
+    /// them. Returns the number of elements appended, which is the minimum of `slice.len()` and
+    /// the remaining capacity, `capacity() - len()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut buffer = vec![4; 1, 2];
+    ///
+    /// assert_eq!(buffer.extend_from_slice_truncating(&[3, 4, 5]), 2);
+    /// assert_eq!(buffer.as_slice(), [1, 2, 3, 4]);
+    /// assert_eq!(buffer.extend_from_slice_truncating(&[6]), 0);
+    /// ```
+    #[inline]
+    #[doc(alias("try_extend_from_slice", "extend_partial", "fill_from"))]
+    pub fn extend_from_slice_truncating(&mut self, slice: &[T]) -> usize
+    where
+        T: Clone,
+    {
+        let count = cmp::min(slice.len(), CAPACITY - self.length);
+        for value in &slice[..count] {
+            self.push_unchecked(value.clone());
+        }
+        count
+    }
+
+    /// Creates a vector with copies of the elements of `src`, copied with a single memory copy.
+    /// It is the counterpart of [`Vec::extend_from_slice()`] for [`Copy`] types, which clones the
+    /// elements one by one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if `src` has more than `CAPACITY` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let received = [0x45, 0x00, 0x00, 0x1C];
+    ///
+    /// let packet = Vec::<u8, 1500>::from_slice_copy(&received).unwrap();
+    /// assert_eq!(packet.as_slice(), received);
+    ///
+    /// assert!(Vec::<u8, 2>::from_slice_copy(&received).is_err());
+    /// ```
+    #[inline]
+    #[doc(alias("from_slice", "copy_from_slice", "memcpy"))]
+    pub const fn from_slice_copy(src: &[T]) -> Result<Self, CapacityError>
+    where
+        T: Copy,
+    {
+        if src.len() > CAPACITY {
+            return Err(CapacityError);
+        }
+
+        let mut vec = Self::new();
+        let dst = vec.data.as_mut_ptr().cast::<T>();
+
+        // SAFETY:
+        // - `src` has at most `CAPACITY` elements, so they fit into the storage.
+        // - `MaybeUninit<T>` has the same layout as `T`.
+        // - The storage of a new vector cannot overlap with `src`.
+        unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst, src.len()) }
+        vec.length = src.len();
+
+        Ok(vec)
+    }
+
     /// Moves elements of `other` vector at the end of the current vector. `other` will be empty.
     ///
     /// # Errors
@@ -686,885 +1151,7669 @@ impl<T, const CAPACITY: usize> Vec<T, CAPACITY> {
         Ok(())
     }
 
-    /// Adds the given `value` to the end of the vector without checking bounds.
-    /// For internal and controlled use only.
-    fn push_unchecked(&mut self, value: T) {
-        debug_assert!(!self.is_full(), "cannot push to full vector");
-        self.data[self.length].write(value);
-        self.length += 1;
+    /// Moves all elements of `other` to the end of the current vector, leaving `other` empty.
+    /// Unlike [`Vec::append()`], the elements are moved, not cloned, so `T` is not required to
+    /// implement [`Clone`].
+    ///
+    /// The operation is all-or-nothing: either all elements of `other` are moved, or none is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if adding elements from `other` would result in current vector
+    /// exceeding its capacity. In this case, neither vector is changed: no element is moved,
+    /// cloned or dropped, and `other` keeps all its elements in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// struct Command(u8);
+    ///
+    /// let mut queue = Vec::<Command, 4>::new();
+    /// queue.push(Command(1)).unwrap();
+    ///
+    /// let mut pending = Vec::<Command, 8>::new();
+    /// pending.push(Command(2)).unwrap();
+    /// pending.push(Command(3)).unwrap();
+    ///
+    /// queue.append_move(&mut pending).unwrap();
+    /// assert!(queue.iter().map(|c| c.0).eq([1, 2, 3]));
+    /// assert!(pending.is_empty());
+    /// ```
+    #[inline]
+    #[doc(alias("extend", "move_from", "take_all"))]
+    pub fn append_move<const OTHER_CAPACITY: usize>(
+        &mut self,
+        other: &mut Vec<T, OTHER_CAPACITY>,
+    ) -> Result<(), CapacityError> {
+        if self.length + other.length > CAPACITY {
+            return Err(CapacityError);
+        }
+
+        other.move_tail_unchecked(0, self);
+
+        Ok(())
     }
 
-    /// Drops all elements in given range. Needed when elements are considered to be going out of
-    /// scope. E.g.: when the vector is going out of scope, when methods such as
-    /// [`Vec::clear()`] and [`Vec::set_len()`] are called.
-    fn drop_range(&mut self, from: usize, to: usize) {
-        for i in from..to {
-            // SAFETY:
-            // - `i` is within bounds of `self.data`.
-            // - The element at `i` has been initialized.
-            unsafe {
-                self.data[i].assume_init_drop();
+    /// Moves all elements of `other` to the end of the current vector if they all fit, leaving
+    /// `other` empty. Same as [`Vec::append_move()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if adding elements from `other` would result in current vector
+    /// exceeding its capacity. In this case, neither vector is changed: no element is moved,
+    /// cloned or dropped, and `other` keeps all its elements in the same order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut frame = vec![4; 1, 2];
+    /// let mut payload = vec![3, 4, 5];
+    ///
+    /// assert!(frame.try_append_exact(&mut payload).is_err());
+    /// assert_eq!(payload.as_slice(), [3, 4, 5]);
+    ///
+    /// payload.pop();
+    /// frame.try_append_exact(&mut payload).unwrap();
+    /// assert_eq!(frame.as_slice(), [1, 2, 3, 4]);
+    /// assert!(payload.is_empty());
+    /// ```
+    #[inline]
+    #[doc(alias("extend", "move_from", "take_all"))]
+    pub fn try_append_exact<const OTHER_CAPACITY: usize>(
+        &mut self,
+        other: &mut Vec<T, OTHER_CAPACITY>,
+    ) -> Result<(), CapacityError> {
+        self.append_move(other)
+    }
+
+    /// Retains only the elements for which the predicate returns true. A rejected element is
+    /// replaced by the last element of the vector, which is then checked in turn.
+    ///
+    /// The order of the elements is not preserved. In exchange, fewer elements are moved than with
+    /// an order-preserving filter.
+    ///
+    /// If the predicate panics, the vector keeps every element that was not removed yet, so no
+    /// element is leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// vec.retain_swap(|n| *n % 2 == 0);
+    /// assert_eq!(vec.as_slice(), [6, 2, 4]);
+    /// ```
+    #[doc(alias("filter", "remove", "swap_remove"))]
+    pub fn retain_swap<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        let mut index = 0;
+        while index < self.length {
+            if predicate(&self.as_slice()[index]) {
+                index += 1;
+            } else {
+                let last = self.length - 1;
+                self.as_mut_slice().swap(index, last);
+                drop(self.pop());
             }
         }
     }
-}
 
-impl<T, const CAPACITY: usize> Drop for Vec<T, CAPACITY> {
-    fn drop(&mut self) {
-        self.drop_range(0, self.length);
+    /// Converts the vector into an array of `N` elements, moving the elements out of the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the vector is not full or if `N` is not equal to `CAPACITY`. In
+    /// this case, the vector is consumed and its elements are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{CapacityError, Vec};
+    ///
+    /// fn parse_header(bytes: &[u8]) -> Result<[u8; 4], CapacityError> {
+    ///     let mut header = Vec::<u8, 4>::new();
+    ///     for byte in bytes.iter().take(4) {
+    ///         header.push(*byte)?;
+    ///     }
+    ///
+    ///     header.into_full_array()
+    /// }
+    ///
+    /// assert_eq!(parse_header(&[1, 2, 3, 4, 5]).unwrap(), [1, 2, 3, 4]);
+    /// assert!(parse_header(&[1, 2]).is_err());
+    /// ```
+    #[inline]
+    #[doc(alias("into_array", "to_array"))]
+    pub fn into_full_array<const N: usize>(self) -> Result<[T; N], CapacityError> {
+        if N != CAPACITY || self.length != N {
+            return Err(CapacityError);
+        }
+
+        let vec = ManuallyDrop::new(self);
+
+        // SAFETY:
+        // - All `N` elements are initialized because the vector is full and `N == CAPACITY`.
+        // - `MaybeUninit<T>` has the same layout as `T`.
+        // - The vector is not dropped, so the elements are moved out exactly once.
+        Ok(unsafe { ptr::read(vec.data.as_ptr().cast::<[T; N]>()) })
     }
-}
 
-impl<T: Clone, const CAPACITY: usize> Clone for Vec<T, CAPACITY> {
-    fn clone(&self) -> Self {
-        let mut vec = Self::new();
+    /// Moves the elements into a vector of vectors with `CH` elements each, in order. The last
+    /// chunk has fewer elements if the length is not a multiple of `CH`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the vector unchanged if the number of chunks exceeds `NCHUNKS`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `CH` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let buffer = vec![10; 1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let pages: Vec<Vec<i32, 3>, 3> = buffer.into_chunks().unwrap();
+    /// assert_eq!(pages.first().map(Vec::as_slice), Some([1, 2, 3].as_slice()));
+    /// assert_eq!(pages.get(1).map(Vec::as_slice), Some([4, 5, 6].as_slice()));
+    /// assert_eq!(pages.last().map(Vec::as_slice), Some([7].as_slice()));
+    /// ```
+    #[inline]
+    #[doc(alias("chunks", "pages", "partition", "split"))]
+    pub fn into_chunks<const CH: usize, const NCHUNKS: usize>(
+        self,
+    ) -> Result<Vec<Vec<T, CH>, NCHUNKS>, Self> {
+        if self.as_slice().chunks(CH).len() > NCHUNKS {
+            return Err(self);
+        }
+
+        let mut chunks = Vec::new();
+        let mut chunk = Vec::new();
         for value in self {
-            vec.push_unchecked(value.clone());
+            chunk.push_unchecked(value);
+            if chunk.is_full() {
+                chunks.push_unchecked(chunk.take());
+            }
         }
-        vec
+        if !chunk.is_empty() {
+            chunks.push_unchecked(chunk);
+        }
+
+        Ok(chunks)
     }
-}
 
-impl<T: PartialEq, const CAPACITY: usize, const OTHER_CAPACITY: usize>
-    PartialEq<Vec<T, OTHER_CAPACITY>> for Vec<T, CAPACITY>
-{
-    fn eq(&self, other: &Vec<T, OTHER_CAPACITY>) -> bool {
-        self.len() == other.len() && self.as_slice() == other.as_slice()
+    /// Moves the elements for which `predicate` returns true into the first returned vector and
+    /// the others into the second one, preserving their order. `predicate` is called exactly once
+    /// for each element, before any element is moved.
+    ///
+    /// # Errors
+    ///
+    /// Returns the vector unchanged if the matching elements do not fit into `A` or the others do
+    /// not fit into `B`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let records = vec![3, -1, 7, 0, -5];
+    ///
+    /// let (valid, invalid): (Vec<i32, 5>, Vec<i32, 5>) =
+    ///     records.partition_into(|record| *record > 0).unwrap();
+    /// assert_eq!(valid.as_slice(), [3, 7]);
+    /// assert_eq!(invalid.as_slice(), [-1, 0, -5]);
+    /// ```
+    #[inline]
+    #[doc(alias("partition", "split", "drain_filter_into"))]
+    pub fn partition_into<const A: usize, const B: usize, F: FnMut(&T) -> bool>(
+        self,
+        mut predicate: F,
+    ) -> Result<(Vec<T, A>, Vec<T, B>), Self> {
+        let mut matches = [false; CAPACITY];
+        let mut matching = 0;
+        for (index, element) in self.iter().enumerate() {
+            matches[index] = predicate(element);
+            matching += usize::from(matches[index]);
+        }
+
+        if matching > A || self.length - matching > B {
+            return Err(self);
+        }
+
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for (index, value) in self.into_iter().enumerate() {
+            if matches[index] {
+                left.push_unchecked(value);
+            } else {
+                right.push_unchecked(value);
+            }
+        }
+
+        Ok((left, right))
     }
-}
 
-impl<T: PartialOrd, const CAPACITY: usize, const OTHER_CAPACITY: usize>
-    PartialOrd<Vec<T, OTHER_CAPACITY>> for Vec<T, CAPACITY>
-{
-    fn partial_cmp(&self, other: &Vec<T, OTHER_CAPACITY>) -> Option<cmp::Ordering> {
-        self.as_slice().partial_cmp(other.as_slice())
+    /// Returns an array with clones of the first `N` elements of the vector, or [`None`] if the
+    /// vector has less than `N` elements. The vector is not changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let packet = vec![0xCA, 0xFE, 1, 2, 3];
+    ///
+    /// assert_eq!(packet.to_array::<2>(), Some([0xCA, 0xFE]));
+    /// assert_eq!(packet.to_array::<6>(), None);
+    /// assert_eq!(packet.len(), 5);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("header", "prefix", "first_chunk"))]
+    pub fn to_array<const N: usize>(&self) -> Option<[T; N]>
+    where
+        T: Clone,
+    {
+        let head = self.as_slice().get(..N)?;
+        Some(array::from_fn(|index| head[index].clone()))
     }
-}
 
-/// Immutable iterator over a [`Vec`].
-///
-/// Created by calling [`Vec::iter()`].
-#[must_use = "must consume iterator"]
-pub struct Iter<'a, T> {
-    data: &'a [MaybeUninit<T>],
-    size: usize,
-    index: usize,
-}
+    /// Returns an array of references to the first `N` elements of the vector, or [`None`] if the
+    /// vector has less than `N` elements. See [`array::each_ref()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let header = vec![0x01, 0x02, 0x7F, 0xAA];
+    ///
+    /// let [version, kind, length] = header.each_ref().unwrap();
+    /// assert_eq!((*version, *kind, *length), (0x01, 0x02, 0x7F));
+    ///
+    /// assert!(header.each_ref::<5>().is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("first_chunk", "destructure"))]
+    pub fn each_ref<const N: usize>(&self) -> Option<[&T; N]> {
+        Some(self.as_slice().first_chunk::<N>()?.each_ref())
+    }
 
-impl<'a, T> Iter<'a, T> {
-    /// Creates immutable iterator.
+    /// Returns an array of mutable references to the first `N` elements of the vector, or
+    /// [`None`] if the vector has less than `N` elements. See [`array::each_mut()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut header = vec![0x01, 0x02, 0x7F, 0xAA];
+    ///
+    /// let [version, _, length] = header.each_mut().unwrap();
+    /// *version += 1;
+    /// *length = 0;
+    /// assert_eq!(header.as_slice(), [0x02, 0x02, 0x00, 0xAA]);
+    /// ```
+    #[must_use]
     #[inline]
-    pub const fn new(data: &'a [MaybeUninit<T>], size: usize) -> Self {
-        Self { data, size, index: 0 }
+    #[doc(alias("first_chunk_mut", "destructure"))]
+    pub fn each_mut<const N: usize>(&mut self) -> Option<[&mut T; N]> {
+        Some(self.as_mut_slice().first_chunk_mut::<N>()?.each_mut())
     }
-}
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Returns a slice of [`Cell`]s over the elements of the vector, allowing individual elements
+    /// to be mutated through shared references.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    ///
+    /// let cells = vec.as_cells();
+    /// for (cell, next) in cells.iter().zip(&cells[1..]) {
+    ///     cell.set(cell.get() + next.get());
+    /// }
+    ///
+    /// assert_eq!(vec.as_slice(), [3, 5, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("as_slice_of_cells", "cell"))]
+    pub fn as_cells(&mut self) -> &[Cell<T>] {
+        Cell::from_mut(self.as_mut_slice()).as_slice_of_cells()
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index == self.size {
-            None
-        } else {
-            // SAFETY:
-            // - `self.index` is within bounds of `self.data`.
-            // - The element at `self.index` has been initialized.
-            let value = unsafe { &*self.data[self.index].as_ptr() };
-            self.index += 1;
-            Some(value)
-        }
+    /// Returns an iterator over `chunk_size` elements of the vector at a time, starting at the end
+    /// of the vector. If `chunk_size` does not divide the length, the first up to `chunk_size - 1`
+    /// elements are omitted and can be retrieved from the `remainder` function of the iterator.
+    ///
+    /// See [`slice::rchunks_exact()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let mut chunks = vec.rchunks_exact(2);
+    /// assert_eq!(chunks.next(), Some(&[4, 5][..]));
+    /// assert_eq!(chunks.next(), Some(&[2, 3][..]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.remainder(), [1]);
+    /// ```
+    #[inline]
+    pub fn rchunks_exact(&self, chunk_size: usize) -> slice::RChunksExact<'_, T> {
+        self.as_slice().rchunks_exact(chunk_size)
     }
-}
 
-impl<'a, T: 'a, const CAPACITY: usize> IntoIterator for &'a Vec<T, CAPACITY> {
-    type IntoIter = Iter<'a, T>;
-    type Item = &'a T;
+    /// Returns an iterator over mutable `chunk_size` elements of the vector at a time, starting at
+    /// the end of the vector. The chunks do not overlap. If `chunk_size` does not divide the length
+    /// of the vector, the last chunk has less than `chunk_size` elements.
+    ///
+    /// See [`slice::rchunks_mut()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![u8; 5; 5];
+    ///
+    /// for (block, chunk) in vec.rchunks_mut(2).enumerate() {
+    ///     chunk.fill(b'a' + u8::try_from(block).unwrap());
+    /// }
+    /// assert_eq!(vec.as_slice(), b"cbbaa");
+    /// ```
+    #[inline]
+    pub fn rchunks_mut(&mut self, chunk_size: usize) -> slice::RChunksMut<'_, T> {
+        self.as_mut_slice().rchunks_mut(chunk_size)
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter()
+    /// Calls `f` on each consecutive mutable chunk of `chunk_size` elements of the vector, from
+    /// front to back. If `chunk_size` does not divide the length of the vector, the last chunk has
+    /// less than `chunk_size` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut samples = vec![1, 2, 3, 4, 5];
+    ///
+    /// samples.for_each_chunk_mut(2, |block| block.reverse());
+    /// assert_eq!(samples.as_slice(), [2, 1, 4, 3, 5]);
+    /// ```
+    #[inline]
+    #[doc(alias("chunks_mut", "blocks"))]
+    pub fn for_each_chunk_mut<F: FnMut(&mut [T])>(&mut self, chunk_size: usize, f: F) {
+        self.as_mut_slice().chunks_mut(chunk_size).for_each(f);
     }
-}
 
-/// Mutable iterator over a [`Vec`].
-///
-/// Created by calling [`Vec::iter_mut()`].
-#[must_use = "must consume iterator"]
-pub struct IterMut<'a, T> {
-    data: &'a mut [MaybeUninit<T>],
-    size: usize,
-    index: usize,
-}
+    /// Returns an iterator over subslices separated by elements that match `predicate`. The
+    /// matched element is not contained in the subslices.
+    ///
+    /// See [`slice::split()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![b'a', b'\n', b'b', b'c', b'\n'];
+    ///
+    /// let mut lines = vec.split(|b| *b == b'\n');
+    /// assert_eq!(lines.next(), Some(&b"a"[..]));
+    /// assert_eq!(lines.next(), Some(&b"bc"[..]));
+    /// assert_eq!(lines.next(), Some(&b""[..]));
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    #[inline]
+    pub fn split<F: FnMut(&T) -> bool>(&self, predicate: F) -> slice::Split<'_, T, F> {
+        self.as_slice().split(predicate)
+    }
+
+    /// Returns an iterator over at most `n` subslices separated by elements that match
+    /// `predicate`. The matched element is not contained in the subslices. The last subslice
+    /// returned contains the rest of the vector.
+    ///
+    /// See [`slice::splitn()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 0, 2, 3, 0, 4];
+    ///
+    /// let mut parts = vec.splitn(2, |n| *n == 0);
+    /// assert_eq!(parts.next(), Some(&[1][..]));
+    /// assert_eq!(parts.next(), Some(&[2, 3, 0, 4][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    #[inline]
+    pub fn splitn<F: FnMut(&T) -> bool>(&self, n: usize, predicate: F) -> slice::SplitN<'_, T, F> {
+        self.as_slice().splitn(n, predicate)
+    }
+
+    /// Returns an iterator over subslices separated by elements that match `predicate`, starting at
+    /// the end of the vector. The matched element is not contained in the subslices.
+    ///
+    /// See [`slice::rsplit()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 0, 2, 3, 0, 4];
+    ///
+    /// let mut parts = vec.rsplit(|n| *n == 0);
+    /// assert_eq!(parts.next(), Some(&[4][..]));
+    /// assert_eq!(parts.next(), Some(&[2, 3][..]));
+    /// assert_eq!(parts.next(), Some(&[1][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    #[inline]
+    pub fn rsplit<F: FnMut(&T) -> bool>(&self, predicate: F) -> slice::RSplit<'_, T, F> {
+        self.as_slice().rsplit(predicate)
+    }
+
+    /// Returns an iterator over at most `n` subslices separated by elements that match
+    /// `predicate`, starting at the end of the vector. The matched element is not contained in the
+    /// subslices. The last subslice returned contains the rest of the vector.
+    ///
+    /// See [`slice::rsplitn()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 0, 2, 3, 0, 4];
+    ///
+    /// let mut parts = vec.rsplitn(2, |n| *n == 0);
+    /// assert_eq!(parts.next(), Some(&[4][..]));
+    /// assert_eq!(parts.next(), Some(&[1, 0, 2, 3][..]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    #[inline]
+    pub fn rsplitn<F: FnMut(&T) -> bool>(
+        &self,
+        n: usize,
+        predicate: F,
+    ) -> slice::RSplitN<'_, T, F> {
+        self.as_slice().rsplitn(n, predicate)
+    }
+
+    /// Returns an iterator over the runs of consecutive elements for which `same_group` returns
+    /// true for each pair of adjacent elements. Each run is yielded as a subslice.
+    ///
+    /// See [`slice::chunk_by()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let readings = vec![1, 1, 2, 3, 3, 3, 1];
+    ///
+    /// let mut runs = readings.group_by(|a, b| a == b);
+    /// assert_eq!(runs.next(), Some(&[1, 1][..]));
+    /// assert_eq!(runs.next(), Some(&[2][..]));
+    /// assert_eq!(runs.next(), Some(&[3, 3, 3][..]));
+    /// assert_eq!(runs.next(), Some(&[1][..]));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    #[inline]
+    #[doc(alias("chunk_by", "runs", "split_when"))]
+    pub fn group_by<F: FnMut(&T, &T) -> bool>(&self, same_group: F) -> slice::ChunkBy<'_, T, F> {
+        self.as_slice().chunk_by(same_group)
+    }
+
+    /// Returns true if the vector and `other` have the same elements, in the same order. Unlike
+    /// `==`, it can be called in `const` contexts, for the element types that implement
+    /// [`ConstEq`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// static TABLE: Vec<u8, 4> = Vec::new();
+    /// static EXPECTED: Vec<u8, 4> = Vec::new();
+    /// const _: () = assert!(TABLE.const_eq(&EXPECTED), "tables should match");
+    ///
+    /// let a = vec![1, 2, 3];
+    /// assert!(a.const_eq(&vec![1, 2, 3]));
+    /// assert!(!a.const_eq(&vec![1, 2, 4]));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("eq", "const_cmp"))]
+    pub const fn const_eq(&self, other: &Self) -> bool
+    where
+        T: ConstEq,
+    {
+        if self.length != other.length {
+            return false;
+        }
+
+        let size = self.byte_len();
+
+        // SAFETY:
+        // - The first `size` bytes of the storage belong to the initialized elements.
+        // - `T: ConstEq` types have no padding, so all these bytes are initialized.
+        let bytes = unsafe { slice::from_raw_parts(self.as_slice().as_ptr().cast::<u8>(), size) };
+
+        // SAFETY: Same as above, as both vectors have the same length.
+        let other_bytes =
+            unsafe { slice::from_raw_parts(other.as_slice().as_ptr().cast::<u8>(), size) };
+
+        let mut index = 0;
+        while index < size {
+            if bytes[index] != other_bytes[index] {
+                return false;
+            }
+            index += 1;
+        }
+
+        true
+    }
+
+    /// Returns a mutable byte slice over the elements of the vector, in native byte order. It
+    /// covers exactly [`Vec::byte_len()`] bytes, never the spare capacity.
+    ///
+    /// Only available for the element types that implement [`Pod`], so any byte written gives
+    /// a valid element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut words = vec![4; 0_u32, 0];
+    /// assert_eq!(words.as_bytes_mut().len(), 8);
+    ///
+    /// words.as_bytes_mut()[4..].fill(0xFF);
+    /// assert_eq!(words.as_slice(), [0, u32::MAX]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("bytes", "as_mut_bytes", "raw"))]
+    pub const fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: Pod,
+    {
+        let size = self.byte_len();
+
+        // SAFETY:
+        // - The first `size` bytes of the storage belong to the initialized elements.
+        // - `T: Pod` types have no padding, so all these bytes are initialized.
+        // - Any byte pattern is a valid `T`, so writing bytes cannot create an invalid element.
+        unsafe { slice::from_raw_parts_mut(self.as_mut_slice().as_mut_ptr().cast::<u8>(), size) }
+    }
+
+    /// Splits the vector around the first element that matches `predicate`, returning the
+    /// elements before and after it, or [`None`] if no element matches. The matched element is
+    /// not contained in either part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![b'k', b'=', b'v', b'=', b'w'];
+    ///
+    /// assert_eq!(vec.split_once(|b| *b == b'='), Some((&b"k"[..], &b"v=w"[..])));
+    /// assert_eq!(vec.split_once(|b| *b == b';'), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn split_once<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<(&[T], &[T])> {
+        let slice = self.as_slice();
+        let index = slice.iter().position(predicate)?;
+        Some((&slice[..index], &slice[index + 1..]))
+    }
+
+    /// Splits the vector around the last element that matches `predicate`, returning the
+    /// elements before and after it, or [`None`] if no element matches. The matched element is
+    /// not contained in either part.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![b'k', b'=', b'v', b'=', b'w'];
+    ///
+    /// assert_eq!(vec.rsplit_once(|b| *b == b'='), Some((&b"k=v"[..], &b"w"[..])));
+    /// assert_eq!(vec.rsplit_once(|b| *b == b';'), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn rsplit_once<F: FnMut(&T) -> bool>(&self, predicate: F) -> Option<(&[T], &[T])> {
+        let slice = self.as_slice();
+        let index = slice.iter().rposition(predicate)?;
+        Some((&slice[..index], &slice[index + 1..]))
+    }
+
+    /// Splits the vector around the first element equal to `value`, returning the elements
+    /// before and after it, or [`None`] if `value` is not found. The matched element is not
+    /// contained in either part.
+    ///
+    /// Shorthand for [`Vec::split_once()`] with an equality predicate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let pair = vec![b'k', b'e', b'y', b'=', b'v', b'a', b'l'];
+    ///
+    /// assert_eq!(pair.split_at_value(&b'='), Some((&b"key"[..], &b"val"[..])));
+    /// assert_eq!(pair.split_at_value(&b':'), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("split_once", "partition", "find"))]
+    pub fn split_at_value(&self, value: &T) -> Option<(&[T], &[T])>
+    where
+        T: PartialEq,
+    {
+        self.split_once(|element| element == value)
+    }
+
+    /// Replaces the element at the specified `index` with `value` and returns the old element, or
+    /// [`None`] if out of bounds. If out of bounds, the vector is not changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    ///
+    /// assert_eq!(vec.replace(1, 4), Some(2));
+    /// assert_eq!(vec.as_slice(), [1, 4, 3]);
+    ///
+    /// assert_eq!(vec.replace(3, 5), None);
+    /// assert_eq!(vec.as_slice(), [1, 4, 3]);
+    /// ```
+    #[inline]
+    #[doc(alias("overwrite", "set", "swap"))]
+    pub fn replace(&mut self, index: usize, value: T) -> Option<T> {
+        self.get_mut(index).map(|element| mem::replace(element, value))
+    }
+
+    /// Inserts `value` at position `index`, shifting all elements after it to the right.
+    ///
+    /// The order of the elements is preserved. To insert in O(1) without preserving the order, use
+    /// [`Vec::swap_insert()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err((index, value))`, giving back the arguments so the insertion can be retried,
+    /// in two cases:
+    /// - the vector is already at full capacity, which can be checked with [`Vec::is_full()`];
+    /// - `index` is greater than the length of the vector.
+    ///
+    /// In both cases, the vector is not changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let mut vec = Vec::<i32, 3>::new();
+    /// vec.push(1).unwrap();
+    /// vec.push(3).unwrap();
+    ///
+    /// vec.insert_if_room(1, 2).unwrap();
+    /// assert_eq!(vec.as_slice(), [1, 2, 3]);
+    ///
+    /// match vec.insert_if_room(0, 0) {
+    ///     Ok(()) => {},
+    ///     Err((index, value)) if vec.is_full() => {
+    ///         // no room left for `value`
+    ///     },
+    ///     Err((index, value)) => {
+    ///         // `index` is out of bounds
+    ///     },
+    /// }
+    /// ```
+    #[inline]
+    #[doc(alias("add", "insert", "put"))]
+    pub fn insert_if_room(&mut self, index: usize, value: T) -> Result<(), (usize, T)> {
+        if self.is_full() || index > self.length {
+            return Err((index, value));
+        }
+
+        self.push_unchecked(value);
+        self.as_mut_slice()[index..].rotate_right(1);
+
+        Ok(())
+    }
+
+    /// Inserts `value` into a vector sorted by `key`, at the position that keeps it sorted, and
+    /// returns that position. The position is found with a binary search on the keys. If there
+    /// are elements with the same key as `value`, it is inserted after them.
+    ///
+    /// If the vector is not sorted by `key`, the position is unspecified, but `value` is still
+    /// inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the vector is full. In this case, `value` is not inserted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// struct Route {
+    ///     metric: u32,
+    ///     name: &'static str,
+    /// }
+    ///
+    /// let mut table = Vec::<Route, 3>::new();
+    /// table.insert_sorted_by_key(Route { metric: 20, name: "b" }, |route| route.metric).unwrap();
+    /// table.insert_sorted_by_key(Route { metric: 10, name: "a" }, |route| route.metric).unwrap();
+    ///
+    /// let index = table.insert_sorted_by_key(Route { metric: 15, name: "c" }, |route| route.metric);
+    /// assert_eq!(index.unwrap(), 1);
+    /// assert!(table.iter().map(|route| route.name).eq(["a", "c", "b"]));
+    /// ```
+    #[inline]
+    #[doc(alias("binary_insert", "sorted_insert", "insert_sorted"))]
+    pub fn insert_sorted_by_key<K: Ord, F: FnMut(&T) -> K>(
+        &mut self,
+        value: T,
+        mut key: F,
+    ) -> Result<usize, CapacityError> {
+        if self.is_full() {
+            return Err(CapacityError);
+        }
+
+        let target = key(&value);
+        let index = self.as_slice().partition_point(|element| key(element) <= target);
+
+        self.push_unchecked(value);
+        self.as_mut_slice()[index..].rotate_right(1);
+
+        Ok(index)
+    }
+
+    /// Sorts the vector and removes duplicate elements, leaving a sorted sequence of unique
+    /// elements. Useful to bring the vector into a canonical form before comparing or hashing it.
+    ///
+    /// The sort is unstable, see [`slice::sort_unstable()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![3, 1, 2, 1, 3];
+    ///
+    /// vec.sort_dedup();
+    /// assert_eq!(vec.as_slice(), [1, 2, 3]);
+    /// ```
+    #[doc(alias("canonicalize", "unique", "set"))]
+    pub fn sort_dedup(&mut self)
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable();
+        self.dedup();
+    }
+
+    /// Removes consecutive repeated elements, preserving the order of the remaining elements.
+    ///
+    /// The first element of each run of equal elements is the one kept; the following ones are
+    /// dropped. If the vector is sorted, all duplicates are removed.
+    ///
+    /// If the comparison panics, no element is removed, though the elements may have been
+    /// reordered, so no element is leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 1, 2, 3, 3, 3, 1];
+    ///
+    /// vec.dedup();
+    /// assert_eq!(vec.as_slice(), [1, 2, 3, 1]);
+    /// ```
+    #[doc(alias("unique", "squeeze", "collapse"))]
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        if self.length < 2 {
+            return;
+        }
+
+        let mut write = 1;
+        for read in 1..self.length {
+            let slice = self.as_mut_slice();
+            if slice[read] != slice[write - 1] {
+                slice.swap(read, write);
+                write += 1;
+            }
+        }
+
+        self.truncate_unchecked(write);
+    }
+
+    /// Removes all repeated elements, wherever they are, preserving the order of the remaining
+    /// elements. Unlike [`Vec::dedup()`], the elements do not have to be consecutive.
+    ///
+    /// The first occurrence of each distinct element is the one kept; the following ones are
+    /// dropped. Each element is compared with all the kept elements before it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 1, 3, 2];
+    ///
+    /// vec.retain_unique();
+    /// assert_eq!(vec.as_slice(), [1, 2, 3]);
+    /// ```
+    #[doc(alias("unique", "distinct", "dedup_all"))]
+    pub fn retain_unique(&mut self)
+    where
+        T: PartialEq,
+    {
+        if self.length < 2 {
+            return;
+        }
+
+        let mut write = 1;
+        for read in 1..self.length {
+            let slice = self.as_mut_slice();
+            if !slice[..write].contains(&slice[read]) {
+                slice.swap(read, write);
+                write += 1;
+            }
+        }
+
+        self.truncate_unchecked(write);
+    }
+
+    /// Returns a new vector with a `(key, length)` pair for each run of consecutive elements with
+    /// equal keys, in order. The key of a run is the key of its first element.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the number of runs exceeds `R`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let readings = vec![20, 20, 20, 21, 21, 20];
+    ///
+    /// let runs: Vec<(i32, usize), 3> = readings.run_length_encode(|reading| *reading).unwrap();
+    /// assert_eq!(runs.as_slice(), [(20, 3), (21, 2), (20, 1)]);
+    /// ```
+    #[inline]
+    #[doc(alias("rle", "runs", "group_by", "dedup"))]
+    pub fn run_length_encode<K: PartialEq, const R: usize, F: FnMut(&T) -> K>(
+        &self,
+        mut key: F,
+    ) -> Result<Vec<(K, usize), R>, CapacityError> {
+        let mut runs = Vec::<(K, usize), R>::new();
+        for element in self {
+            let element_key = key(element);
+            if let Some(run) = runs.last_mut().filter(|run| run.0 == element_key) {
+                run.1 += 1;
+            } else {
+                runs.push((element_key, 1))?;
+            }
+        }
+
+        Ok(runs)
+    }
+
+    /// Splits the vector into two vectors of the same capacity, moving the elements. The first
+    /// vector contains the first half of the elements and the second vector contains the rest.
+    ///
+    /// If the length is odd, the first vector gets the extra element: it contains
+    /// `ceil(length / 2)` elements and the second one contains `floor(length / 2)` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let (front, back) = vec.split_into_halves();
+    /// assert_eq!(front.as_slice(), [1, 2, 3]);
+    /// assert_eq!(back.as_slice(), [4, 5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("split", "halve", "bisect"))]
+    pub fn split_into_halves(mut self) -> (Self, Self) {
+        let mut back = Self::new();
+        self.move_tail_unchecked(self.length.div_ceil(2), &mut back);
+        (self, back)
+    }
+
+    /// Returns a new vector with the elements of the vector and `other` taken alternately,
+    /// starting with the vector: `self[0], other[0], self[1], other[1], ...`. When one of the
+    /// inputs runs out of elements, the remaining elements of the longer one are appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the combined length of the two vectors exceeds `R`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let left = vec![1, 3, 5, 7];
+    /// let right = vec![2, 4];
+    ///
+    /// let samples: Vec<i32, 6> = left.interleave(&right).unwrap();
+    /// assert_eq!(samples.as_slice(), [1, 2, 3, 4, 5, 7]);
+    /// ```
+    #[inline]
+    #[doc(alias("zip", "merge", "alternate"))]
+    pub fn interleave<const OTHER_CAPACITY: usize, const R: usize>(
+        &self,
+        other: &Vec<T, OTHER_CAPACITY>,
+    ) -> Result<Vec<T, R>, CapacityError>
+    where
+        T: Clone,
+    {
+        if self.len() + other.len() > R {
+            return Err(CapacityError);
+        }
+
+        let mut vec = Vec::new();
+        for index in 0..cmp::max(self.len(), other.len()) {
+            if let Some(value) = self.get(index) {
+                vec.push_unchecked(value.clone());
+            }
+            if let Some(value) = other.get(index) {
+                vec.push_unchecked(value.clone());
+            }
+        }
+
+        Ok(vec)
+    }
+
+    /// Returns a new vector with the results of calling `f` on the elements of the vector and
+    /// `other` at the same index: `f(&self[0], &other[0]), f(&self[1], &other[1]), ...`. When the
+    /// two inputs have different lengths, it stops at the end of the shorter one, so the result has
+    /// `min(self.len(), other.len())` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the length of the result exceeds `R`. In this case, `f` is not
+    /// called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let left = vec![1, 2, 3, 4];
+    /// let right = vec![10, 20, 30];
+    ///
+    /// let sums: Vec<i32, 3> = left.zip_with(&right, |a, b| a + b).unwrap();
+    /// assert_eq!(sums.as_slice(), [11, 22, 33]);
+    /// ```
+    #[inline]
+    #[doc(alias("zip", "map2", "combine"))]
+    pub fn zip_with<U, V, const OTHER_CAPACITY: usize, const R: usize, F: FnMut(&T, &U) -> V>(
+        &self,
+        other: &Vec<U, OTHER_CAPACITY>,
+        mut f: F,
+    ) -> Result<Vec<V, R>, CapacityError> {
+        if cmp::min(self.len(), other.len()) > R {
+            return Err(CapacityError);
+        }
+
+        let mut vec = Vec::new();
+        for (a, b) in self.iter().zip(other) {
+            vec.push_unchecked(f(a, b));
+        }
+
+        Ok(vec)
+    }
+
+    /// Returns a new vector with the results of calling `f` on each window of `W` consecutive
+    /// elements of the vector: `f(&[self[0], ..., self[W - 1]])`, `f(&[self[1], ..., self[W]])`,
+    /// and so on. The windows overlap, so the result has `len() - W + 1` elements, or none if the
+    /// vector has less than `W` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the length of the result exceeds `R`. In this case, `f` is not
+    /// called.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `W` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let samples = vec![2, 4, 6, 8, 10];
+    ///
+    /// let averages: Vec<i32, 3> = samples.windows_map(|&[a, b, c]| (a + b + c) / 3).unwrap();
+    /// assert_eq!(averages.as_slice(), [4, 6, 8]);
+    /// ```
+    #[inline]
+    #[doc(alias("windows", "moving", "sliding"))]
+    pub fn windows_map<const W: usize, U, F: FnMut(&[T; W]) -> U, const R: usize>(
+        &self,
+        mut f: F,
+    ) -> Result<Vec<U, R>, CapacityError> {
+        if self.as_slice().windows(W).len() > R {
+            return Err(CapacityError);
+        }
+
+        let mut vec = Vec::new();
+        let mut rest = self.as_slice();
+        while let Some((window, _)) = rest.split_first_chunk::<W>() {
+            vec.push_unchecked(f(window));
+            rest = &rest[1..];
+        }
+
+        Ok(vec)
+    }
+
+    /// Returns a new vector with clones of the elements of all the arrays in `nested`, in order.
+    /// The result has exactly `nested.len() * K` elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the length of the result, `nested.len() * K`, exceeds `R` or
+    /// overflows [`usize`]. In this case, no element is cloned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let records = vec![4; [1, 2], [3, 4], [5, 6]];
+    ///
+    /// let flat: Vec<u8, 6> = Vec::flatten(&records).unwrap();
+    /// assert_eq!(flat.as_slice(), [1, 2, 3, 4, 5, 6]);
+    /// ```
+    #[inline]
+    #[doc(alias("concat", "join", "flat"))]
+    pub fn flatten<const K: usize, const R: usize>(
+        nested: &Vec<[T; K], CAPACITY>,
+    ) -> Result<Vec<T, R>, CapacityError>
+    where
+        T: Clone,
+    {
+        if nested.len().checked_mul(K).is_none_or(|length| length > R) {
+            return Err(CapacityError);
+        }
+
+        let mut vec = Vec::new();
+        for value in nested.iter().flatten() {
+            vec.push_unchecked(value.clone());
+        }
+
+        Ok(vec)
+    }
+
+    /// Returns an iterator over all pairs of adjacent elements: `(self[0], self[1])`,
+    /// `(self[1], self[2])`, and so on. The iterator is empty if the vector has less than two
+    /// elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 4, 9, 16];
+    ///
+    /// let mut deltas = vec.pairs().map(|(a, b)| b - a);
+    /// assert_eq!(deltas.next(), Some(3));
+    /// assert_eq!(deltas.next(), Some(5));
+    /// assert_eq!(deltas.next(), Some(7));
+    /// assert_eq!(deltas.next(), None);
+    /// ```
+    #[inline]
+    #[doc(alias("windows", "adjacent", "tuple_windows"))]
+    pub fn pairs(&self) -> impl Iterator<Item = (&T, &T)> {
+        let slice = self.as_slice();
+        slice.iter().zip(slice.iter().skip(1))
+    }
+
+    /// Removes the elements whose key, as returned by `key`, is one of `removed_keys`. The order of
+    /// the remaining elements is preserved.
+    ///
+    /// The complexity is O(length * `removed_keys.len()`) because each key is searched linearly in
+    /// `removed_keys`.
+    ///
+    /// If the key function panics, the elements that were not visited yet are kept after the ones
+    /// retained so far, so no element is leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// struct Item {
+    ///     id: u32,
+    /// }
+    ///
+    /// let mut table = Vec::<Item, 10>::new();
+    /// for id in 1..=5 {
+    ///     table.push(Item { id }).unwrap();
+    /// }
+    ///
+    /// table.retain_by_key(|item| item.id, &[2, 4, 6]);
+    /// assert!(table.iter().map(|item| item.id).eq([1, 3, 5]));
+    /// ```
+    #[doc(alias("filter", "remove", "delete"))]
+    pub fn retain_by_key<K: PartialEq, F: FnMut(&T) -> K>(
+        &mut self,
+        mut key: F,
+        removed_keys: &[K],
+    ) {
+        self.retain_core(|_, element| ControlFlow::Continue(!removed_keys.contains(&key(element))));
+    }
+
+    /// Calls `f` with a slice of the entire vector and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![3, 1, 2];
+    ///
+    /// let max = vec.with_slice(|slice| slice.iter().copied().max());
+    /// assert_eq!(max, Some(3));
+    /// ```
+    #[inline]
+    pub fn with_slice<R, F: FnOnce(&[T]) -> R>(&self, f: F) -> R {
+        f(self.as_slice())
+    }
+
+    /// Calls `f` with a mutable slice of the entire vector and returns its result.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![3, 1, 2];
+    ///
+    /// let first = vec.with_slice_mut(|slice| {
+    ///     slice.sort_unstable();
+    ///     slice[0]
+    /// });
+    /// assert_eq!(first, 1);
+    /// assert_eq!(vec.as_slice(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn with_slice_mut<R, F: FnOnce(&mut [T]) -> R>(&mut self, f: F) -> R {
+        f(self.as_mut_slice())
+    }
+
+    /// Returns the number of elements for which the predicate returns true.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 1, 3, 1];
+    ///
+    /// assert_eq!(vec.count_where(|n| *n == 1), 3);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("count", "filter"))]
+    pub fn count_where<F: FnMut(&T) -> bool>(&self, mut predicate: F) -> usize {
+        self.iter().filter(|element| predicate(element)).count()
+    }
+
+    /// Returns the first non-[`None`] result of calling `f` on the elements of the vector, in
+    /// order, or [`None`] if `f` returns [`None`] for all of them. Stops at the first match.
+    ///
+    /// See [`Iterator::find_map()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let mut table = Vec::<(&str, u32), 4>::new();
+    /// table.push_array([("eth0", 1500), ("lo", 65536), ("wlan0", 1500)]).unwrap();
+    ///
+    /// assert_eq!(table.find_map(|&(name, mtu)| (name == "lo").then_some(mtu)), Some(65536));
+    /// assert_eq!(table.find_map(|&(name, mtu)| (name == "ppp0").then_some(mtu)), None);
+    /// ```
+    #[inline]
+    #[doc(alias("find", "lookup", "filter_map"))]
+    pub fn find_map<B, F: FnMut(&T) -> Option<B>>(&self, f: F) -> Option<B> {
+        self.iter().find_map(f)
+    }
+
+    /// Returns true if `f` returns true for all elements of the vector. Stops at the first
+    /// element for which `f` returns false.
+    ///
+    /// An empty vector returns true.
+    ///
+    /// See [`Iterator::all()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// assert!(vec![2, 4, 6].all(|n| n % 2 == 0));
+    /// assert!(!vec![2, 3, 6].all(|n| n % 2 == 0));
+    /// assert!(Vec::<i32, 3>::new().all(|_| false));
+    /// ```
+    #[inline]
+    #[doc(alias("every", "for_all"))]
+    pub fn all<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().all(f)
+    }
+
+    /// Returns true if `f` returns true for at least one element of the vector. Stops at the
+    /// first element for which `f` returns true.
+    ///
+    /// An empty vector returns false.
+    ///
+    /// See [`Iterator::any()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// assert!(vec![1, 2, 3].any(|n| *n > 2));
+    /// assert!(!vec![1, 2, 3].any(|n| *n > 3));
+    /// assert!(!Vec::<i32, 3>::new().any(|_| true));
+    /// ```
+    #[inline]
+    #[doc(alias("some", "exists"))]
+    pub fn any<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+        self.iter().any(f)
+    }
+
+    /// Returns true if the elements read the same forwards and backwards. An empty vector and a
+    /// vector with one element are palindromes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// assert!(vec![0xA5, 0x3C, 0xA5].is_palindrome());
+    /// assert!(!vec![0xA5, 0x3C].is_palindrome());
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("symmetric", "reversed_eq"))]
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        let slice = self.as_slice();
+        let half = slice.len().div_euclid(2);
+        slice[..half].iter().eq(slice[slice.len() - half..].iter().rev())
+    }
+
+    /// Returns the number of leading elements that are equal in the vector and `other`.
+    ///
+    /// Comparison stops at the first mismatch or at the end of the shorter sequence, so the
+    /// result is 0 if either of them is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let cached = vec![0x01, 0x02, 0x03, 0x04];
+    ///
+    /// assert_eq!(cached.common_prefix_len(&[0x01, 0x02, 0xFF, 0x04]), 2);
+    /// assert_eq!(cached.common_prefix_len(&[0x01, 0x02]), 2);
+    /// assert_eq!(cached.common_prefix_len(&[]), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("mismatch", "diverge"))]
+    pub fn common_prefix_len(&self, other: &[T]) -> usize
+    where
+        T: PartialEq,
+    {
+        self.iter().zip(other).take_while(|&(a, b)| a == b).count()
+    }
+
+    /// Returns a reference to the element that gives the maximum value from the specified
+    /// function, or [`None`] if the vector is empty. If several elements are equally maximum, the
+    /// last one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![-3_i32, 0, 1, 5, -10];
+    ///
+    /// assert_eq!(vec.max_by_key(|n| n.abs()), Some(&-10));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("max", "best", "largest"))]
+    pub fn max_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> Option<&T> {
+        self.iter().max_by_key(|element| f(element))
+    }
+
+    /// Returns a reference to the element that gives the minimum value from the specified
+    /// function, or [`None`] if the vector is empty. If several elements are equally minimum, the
+    /// first one is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![-3_i32, 0, 1, 5, -10];
+    ///
+    /// assert_eq!(vec.min_by_key(|n| n.abs()), Some(&0));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("min", "smallest"))]
+    pub fn min_by_key<B: Ord, F: FnMut(&T) -> B>(&self, mut f: F) -> Option<&T> {
+        self.iter().min_by_key(|element| f(element))
+    }
+
+    /// Returns the index of the maximum element, or [`None`] if the vector is empty. If several
+    /// elements are equally maximum, the index of the first one is returned.
+    ///
+    /// An element only takes the place of the current maximum if it compares greater, so elements
+    /// that cannot be compared, like NaN, are skipped, unless they come first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let samples = vec![3, 9, 4, 9, 1];
+    ///
+    /// assert_eq!(samples.argmax(), Some(1));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("max", "peak", "position_max"))]
+    pub fn argmax(&self) -> Option<usize>
+    where
+        T: PartialOrd,
+    {
+        self.position_by(|element, max| element > max)
+    }
+
+    /// Returns the index of the minimum element, or [`None`] if the vector is empty. If several
+    /// elements are equally minimum, the index of the first one is returned.
+    ///
+    /// An element only takes the place of the current minimum if it compares less, so elements
+    /// that cannot be compared, like NaN, are skipped, unless they come first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let samples = vec![3, 1, 4, 1, 5];
+    ///
+    /// assert_eq!(samples.argmin(), Some(1));
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("min", "position_min"))]
+    pub fn argmin(&self) -> Option<usize>
+    where
+        T: PartialOrd,
+    {
+        self.position_by(|element, min| element < min)
+    }
+
+    /// Sums the elements of the vector. An empty vector returns the zero value of the type.
+    ///
+    /// See [`Iterator::sum()`].
+    ///
+    /// # Panics
+    ///
+    /// Like [`Iterator::sum()`], panics on overflow of primitive integers if overflow checks are
+    /// enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 3];
+    ///
+    /// assert_eq!(vec.sum::<i32>(), 6);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("total", "add"))]
+    pub fn sum<'a, S: Sum<&'a T>>(&'a self) -> S {
+        self.iter().sum()
+    }
+
+    /// Multiplies the elements of the vector. An empty vector returns the one value of the type.
+    ///
+    /// See [`Iterator::product()`].
+    ///
+    /// # Panics
+    ///
+    /// Like [`Iterator::product()`], panics on overflow of primitive integers if overflow checks
+    /// are enabled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![2, 3, 4];
+    ///
+    /// assert_eq!(vec.product::<i32>(), 24);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("multiply"))]
+    pub fn product<'a, P: Product<&'a T>>(&'a self) -> P {
+        self.iter().product()
+    }
+
+    /// Sums the elements of the vector with wrapping arithmetic, so unlike [`Vec::sum()`] it never
+    /// panics on overflow. An empty vector returns zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let frame = vec![0xF0_u8, 0x20, 0x05];
+    ///
+    /// assert_eq!(frame.wrapping_sum(), 0x15);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("checksum", "wrapping_add"))]
+    pub fn wrapping_sum(&self) -> T
+    where
+        T: WrappingAdd,
+    {
+        self.wrapping_checksum()
+    }
+
+    /// Sums the elements of the vector into an accumulator of type `S` with wrapping arithmetic,
+    /// so it never panics on overflow. A wider `S` gives a checksum with fewer collisions over
+    /// byte buffers. An empty vector returns zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let frame = vec![0xF0_u8, 0x20, 0x05];
+    ///
+    /// assert_eq!(frame.wrapping_checksum::<u8>(), 0x15);
+    /// assert_eq!(frame.wrapping_checksum::<u16>(), 0x115);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("checksum", "wrapping_add"))]
+    pub fn wrapping_checksum<S: WrappingAdd + From<T>>(&self) -> S
+    where
+        T: Copy,
+    {
+        self.iter().fold(S::ZERO, |sum, &element| sum.wrapping_add(S::from(element)))
+    }
+
+    /// Folds the elements of the vector into an accumulator by applying `f` to the accumulator and
+    /// each element, from front to back, starting with `init`.
+    ///
+    /// See [`Iterator::fold()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let bytes = vec![0x01_u8, 0xFF, 0x10];
+    ///
+    /// let checksum = bytes.fold(0_u8, |sum, b| sum.wrapping_add(*b));
+    /// assert_eq!(checksum, 0x10);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("reduce", "accumulate", "inject"))]
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.as_slice().iter().fold(init, f)
+    }
+
+    /// Folds the elements of the vector into an accumulator like [`Vec::fold()`], stopping at the
+    /// first error returned by `f`.
+    ///
+    /// See [`Iterator::try_fold()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`. The remaining elements are not visited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let bytes = vec![100_u8, 100, 100];
+    ///
+    /// assert_eq!(bytes.try_fold(0_u8, |sum, b| sum.checked_add(*b).ok_or(())), Err(()));
+    /// assert_eq!(bytes.try_fold(0_u16, |sum, b| Ok::<_, ()>(sum + u16::from(*b))), Ok(300));
+    /// ```
+    #[inline]
+    #[doc(alias("try_reduce", "checked_fold"))]
+    pub fn try_fold<B, E, F: FnMut(B, &T) -> Result<B, E>>(&self, init: B, f: F) -> Result<B, E> {
+        self.as_slice().iter().try_fold(init, f)
+    }
+
+    /// Moves the elements of the given array at the end of the vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if adding the elements would result in the vector exceeding its
+    /// capacity. In this case, no element is added and the array is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// struct Record {
+    ///     id: u32,
+    /// }
+    ///
+    /// let mut vec = Vec::<Record, 4>::new();
+    ///
+    /// vec.push_array([Record { id: 1 }, Record { id: 2 }, Record { id: 3 }]).unwrap();
+    /// assert_eq!(vec.len(), 3);
+    ///
+    /// assert!(vec.push_array([Record { id: 4 }, Record { id: 5 }]).is_err());
+    /// assert_eq!(vec.len(), 3);
+    /// ```
+    #[inline]
+    #[doc(alias("extend", "push_all", "chunked_push"))]
+    pub fn push_array<const N: usize>(&mut self, values: [T; N]) -> Result<(), CapacityError> {
+        if self.len() + N > CAPACITY {
+            return Err(CapacityError);
+        }
+
+        for value in values {
+            self.push_unchecked(value);
+        }
+
+        Ok(())
+    }
+
+    /// Removes and returns the element at the specified `index`, or [`None`] if out of bounds. The
+    /// removed element is replaced by the last element of the vector.
+    ///
+    /// The order of the elements is not preserved, but the removal is O(1).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    ///
+    /// assert_eq!(vec.try_swap_remove(1), Some(2));
+    /// assert_eq!(vec.as_slice(), [1, 4, 3]);
+    ///
+    /// assert_eq!(vec.try_swap_remove(3), None);
+    /// assert_eq!(vec.as_slice(), [1, 4, 3]);
+    /// ```
+    #[inline]
+    #[doc(alias("swap_remove", "remove", "delete"))]
+    pub const fn try_swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        let last = self.length - 1;
+        self.as_mut_slice().swap(index, last);
+        self.pop()
+    }
+
+    /// Removes and returns the element at the specified `index`, shifting all elements after it to
+    /// the left, or returns [`None`] if out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4];
+    ///
+    /// assert_eq!(vec.try_remove(1), Some(2));
+    /// assert_eq!(vec.as_slice(), [1, 3, 4]);
+    ///
+    /// assert_eq!(vec.try_remove(3), None);
+    /// assert_eq!(vec.as_slice(), [1, 3, 4]);
+    /// ```
+    #[inline]
+    #[doc(alias("remove", "delete"))]
+    pub fn try_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        self.as_mut_slice()[index..].rotate_left(1);
+        self.pop()
+    }
+
+    /// Moves the element at `index` to the front of the vector, shifting the elements before it
+    /// to the right by one. The elements after `index` are not moved.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// // most recently used first
+    /// let mut cache = vec!["a", "b", "c", "d"];
+    ///
+    /// cache.rotate_to_front(2);
+    /// assert_eq!(cache.as_slice(), ["c", "a", "b", "d"]);
+    /// ```
+    #[inline]
+    #[doc(alias("move_to_front", "promote", "lru", "rotate_right"))]
+    pub fn rotate_to_front(&mut self, index: usize) {
+        assert!(index < self.length, "index (is {index}) should be < len (is {})", self.length);
+        self.as_mut_slice()[..=index].rotate_right(1);
+    }
+
+    /// Swaps the first `k` elements with the last `k` elements, keeping their order within each
+    /// end: the element at `i` is swapped with the element at `len() - k + i`, for `i` in `0..k`.
+    /// The elements in between are not moved. Does nothing if `k` is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `2 * k` is greater than the length, as the two ends would overlap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut cells = vec![1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// cells.swap_ends(2);
+    /// assert_eq!(cells.as_slice(), [6, 7, 3, 4, 5, 1, 2]);
+    /// ```
+    #[inline]
+    #[doc(alias("swap_with_slice", "mirror", "borders"))]
+    pub fn swap_ends(&mut self, k: usize) {
+        assert!(
+            k <= self.length && k <= self.length - k,
+            "ends length (is {k}) should be <= half of len (is {})",
+            self.length
+        );
+
+        let length = self.length;
+        let (head, tail) = self.as_mut_slice().split_at_mut(length - k);
+        head[..k].swap_with_slice(tail);
+    }
+
+    /// Removes and drops the elements in the given `range`, shifting the elements after it to the
+    /// left to close the gap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the vector's length or if its start is greater than
+    /// its end, as when indexing a slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// vec.remove_range(1..3);
+    /// assert_eq!(vec.as_slice(), [1, 4, 5, 6]);
+    ///
+    /// vec.remove_range(2..);
+    /// assert_eq!(vec.as_slice(), [1, 4]);
+    /// ```
+    #[inline]
+    #[doc(alias("shift_remove_range", "erase", "drain"))]
+    pub fn remove_range<R: RangeBounds<usize>>(&mut self, range: R) {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        let count = self.as_slice()[bounds].len();
+        let start = match bounds.0 {
+            Bound::Included(start) => start,
+            Bound::Excluded(start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+
+        self.as_mut_slice()[start..].rotate_left(count);
+        self.truncate_unchecked(self.length - count);
+    }
+
+    /// Keeps only the last `keep_last` elements, dropping the elements before them and shifting
+    /// the kept elements to the front. Has no effect if `keep_last` is greater than or equal to
+    /// the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// vec.truncate_front(2);
+    /// assert_eq!(vec.as_slice(), [4, 5]);
+    ///
+    /// vec.truncate_front(10);
+    /// assert_eq!(vec.as_slice(), [4, 5]);
+    /// ```
+    #[inline]
+    #[doc(alias("keep_last", "most_recent", "evict"))]
+    pub fn truncate_front(&mut self, keep_last: usize) {
+        self.shift_left(self.length.saturating_sub(keep_last));
+    }
+
+    /// Removes and drops the first `n` elements, shifting the remaining elements to the front.
+    /// Removes all elements if `n` is greater than or equal to the length of the vector, and has
+    /// no effect if `n` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// vec.shift_left(2);
+    /// assert_eq!(vec.as_slice(), [3, 4, 5]);
+    ///
+    /// vec.shift_left(10);
+    /// assert!(vec.is_empty());
+    /// ```
+    #[inline]
+    #[doc(alias("pop_front_n", "drop_first", "evict"))]
+    pub fn shift_left(&mut self, n: usize) {
+        let removed = n.min(self.length);
+        if removed == 0 {
+            return;
+        }
+
+        self.as_mut_slice().rotate_left(removed);
+        self.truncate_unchecked(self.length - removed);
+    }
+
+    /// Inserts elements of given slice at the front of the vector, shifting the existing elements
+    /// to the right.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if adding elements of given slice would result in vector exceeding
+    /// its capacity. In this case, the vector is not changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let mut message = Vec::<u8, 8>::new();
+    /// message.extend_from_slice(b"body").unwrap();
+    ///
+    /// message.prepend_from_slice(b"head").unwrap();
+    /// assert_eq!(message.as_slice(), b"headbody");
+    ///
+    /// assert!(message.prepend_from_slice(b"more").is_err());
+    /// ```
+    #[inline]
+    #[doc(alias("insert_from_slice", "push_front"))]
+    pub fn prepend_from_slice(&mut self, slice: &[T]) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        self.extend_from_slice(slice)?;
+        self.as_mut_slice().rotate_right(slice.len());
+
+        Ok(())
+    }
+
+    /// Retains only the elements for which the predicate returns true, preserving their order.
+    ///
+    /// The elements are visited once, from front to back, and each removed element is dropped
+    /// right after the predicate returns false for it. So removed elements are guaranteed to be
+    /// dropped in their original order, before the predicate is called for the next element.
+    ///
+    /// If the predicate panics, the elements that were not visited yet are kept after the ones
+    /// retained so far, so no element is leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// vec.retain(|n| n % 3 != 0);
+    /// assert_eq!(vec.as_slice(), [1, 2, 4, 5]);
+    /// ```
+    #[inline]
+    #[doc(alias("filter", "keep"))]
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        self.retain_core(|_, element| ControlFlow::Continue(predicate(element)));
+    }
+
+    /// Replaces each element with the value `f` returns for it, from front to back. Unlike
+    /// [`Vec::iter_mut()`], `f` takes ownership of the old element, so elements that are not
+    /// [`Clone`] can be transformed.
+    ///
+    /// If `f` panics, the element it was given is removed from the vector, as it was moved into
+    /// `f`, and the elements that were not visited yet are kept after the ones already replaced,
+    /// so no element is left uninitialized, leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let mut names = Vec::<Option<&str>, 3>::new();
+    /// names.push_array([Some("a"), None, Some("c")]).unwrap();
+    ///
+    /// names.map_in_place(|name| name.or(Some("unknown")));
+    /// assert_eq!(names.as_slice(), [Some("a"), Some("unknown"), Some("c")]);
+    /// ```
+    #[inline]
+    #[doc(alias("transform", "replace_each", "map"))]
+    pub fn map_in_place<F: FnMut(T) -> T>(&mut self, mut f: F) {
+        let length = self.length;
+        self.length = 0;
+        let mut guard = RetainGuard { vec: self, length, read: 0, write: 0 };
+
+        while guard.read < guard.length {
+            let index = guard.read;
+
+            // SAFETY: The element at `index` has been initialized and was neither moved nor
+            // dropped. It is moved out only once, as `guard.read` is advanced past it before `f`
+            // is called, so the guard does not consider it part of the vector anymore.
+            let element = unsafe { guard.vec.data[index].assume_init_read() };
+            guard.read += 1;
+
+            guard.vec.data[index].write(f(element));
+            guard.write += 1;
+        }
+    }
+
+    /// Retains only the elements for which the predicate returns true, like [`Vec::retain()`],
+    /// and returns the remaining capacity: the number of elements that can be pushed afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut jobs = vec![5; 1, 2, 3, 4];
+    ///
+    /// let room = jobs.retain_reporting(|job| *job > 2);
+    /// assert_eq!(room, 3);
+    ///
+    /// for job in 5..5 + room {
+    ///     jobs.push(job).unwrap();
+    /// }
+    /// assert!(jobs.is_full());
+    /// ```
+    #[inline]
+    #[doc(alias("filter", "retain", "refill"))]
+    pub fn retain_reporting<F: FnMut(&T) -> bool>(&mut self, f: F) -> usize {
+        self.retain(f);
+        CAPACITY - self.length
+    }
+
+    /// Retains only the elements for which the predicate returns true, preserving their order.
+    /// The predicate is given the original index of each element, before any element is removed.
+    ///
+    /// If the predicate panics, the elements that were not visited yet are kept after the ones
+    /// retained so far, so no element is leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut samples = vec![10, 11, 12, 13, 14, 15, 16];
+    ///
+    /// // keep every third sample
+    /// samples.retain_indexed(|index, _| index % 3 == 0);
+    /// assert_eq!(samples.as_slice(), [10, 13, 16]);
+    /// ```
+    #[doc(alias("filter", "keep_range", "downsample"))]
+    pub fn retain_indexed<F: FnMut(usize, &T) -> bool>(&mut self, mut predicate: F) {
+        self.retain_core(|index, element| ControlFlow::Continue(predicate(index, element)));
+    }
+
+    /// Sets all elements in the given `range` to clones of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the vector's length, like slice indexing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// vec.fill_range(1..3, 0);
+    /// assert_eq!(vec.as_slice(), [1, 0, 0, 4, 5]);
+    ///
+    /// vec.fill_range(3.., 9);
+    /// assert_eq!(vec.as_slice(), [1, 0, 0, 9, 9]);
+    /// ```
+    #[inline]
+    #[doc(alias("fill", "reset", "set"))]
+    pub fn fill_range<R: RangeBounds<usize>>(&mut self, range: R, value: T)
+    where
+        T: Clone,
+    {
+        self.as_mut_slice()[(range.start_bound().cloned(), range.end_bound().cloned())].fill(value);
+    }
+
+    /// Overwrites every element for which the predicate returns true with a clone of `value`,
+    /// without changing the length. The last matching element takes `value` itself, so no clone
+    /// is made when only one element matches. The overwritten elements are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut samples = vec![3, 120, 7, -40, 101];
+    ///
+    /// samples.replace_all_where(|n| *n > 100, 100);
+    /// assert_eq!(samples.as_slice(), [3, 100, 7, -40, 100]);
+    /// ```
+    #[inline]
+    #[doc(alias("clamp", "normalize", "replace_if"))]
+    pub fn replace_all_where<F: FnMut(&T) -> bool>(&mut self, mut predicate: F, value: T)
+    where
+        T: Clone,
+    {
+        let mut matched: Option<&mut T> = None;
+        for element in self.as_mut_slice() {
+            if predicate(element) {
+                if let Some(previous) = matched.replace(element) {
+                    *previous = value.clone();
+                }
+            }
+        }
+
+        if let Some(last) = matched {
+            *last = value;
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements in the vector, starting at the
+    /// `start` index. If `start` is greater than the length, the iterator is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// for num in vec.iter_mut_from(3) {
+    ///     *num *= 10;
+    /// }
+    /// assert_eq!(vec.as_slice(), [1, 2, 3, 40, 50]);
+    /// ```
+    #[inline]
+    #[doc(alias("skip", "resume"))]
+    pub const fn iter_mut_from(&mut self, start: usize) -> IterMut<'_, T> {
+        let index = if start < self.length { start } else { self.length };
+        IterMut { data: &mut self.data, size: self.length, index }
+    }
+
+    /// Inserts `value` at position `index` by moving the element currently at `index` to the end
+    /// of the vector. If `index` is equal to the length, `value` is added to the end.
+    ///
+    /// The order of the elements is not preserved, but the insertion is O(1). To preserve the
+    /// order by shifting the elements after `index`, use [`Vec::insert_if_room()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the vector is already at full capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let mut vec = Vec::<i32, 4>::new();
+    /// vec.extend_from_slice(&[1, 2, 3]).unwrap();
+    ///
+    /// vec.swap_insert(0, 4).unwrap();
+    /// assert_eq!(vec.as_slice(), [4, 2, 3, 1]);
+    ///
+    /// assert!(vec.swap_insert(0, 5).is_err());
+    /// ```
+    #[inline]
+    #[doc(alias("insert", "add", "put"))]
+    pub fn swap_insert(&mut self, index: usize, value: T) -> Result<(), CapacityError> {
+        self.assert_insertion_index(index);
+
+        self.push(value)?;
+        let last = self.length - 1;
+        self.as_mut_slice().swap(index, last);
+
+        Ok(())
+    }
+
+    /// Sorts the vector and returns an iterator that removes the elements in ascending order. The
+    /// vector is empty after the call, even if the iterator is dropped before being fully
+    /// consumed, in which case the remaining elements are dropped.
+    ///
+    /// The sort is unstable, see [`slice::sort_unstable()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let mut tasks = Vec::<(u8, &str), 10>::new();
+    /// tasks.push_array([(2, "write"), (1, "read"), (3, "close")]).unwrap();
+    ///
+    /// let mut drain = tasks.drain_sorted();
+    /// assert_eq!(drain.next(), Some((1, "read")));
+    /// assert_eq!(drain.next(), Some((2, "write")));
+    /// drop(drain);
+    ///
+    /// assert!(tasks.is_empty());
+    /// ```
+    #[inline]
+    #[doc(alias("drain", "priority", "heap"))]
+    pub fn drain_sorted(&mut self) -> impl Iterator<Item = T>
+    where
+        T: Ord,
+    {
+        self.as_mut_slice().sort_unstable();
+
+        let end = self.length;
+        self.length = 0;
+        Drain { vec: self, index: 0, end }
+    }
+
+    /// Creates a [`Vec`] with `length` elements, where each element is returned by `f` called with
+    /// its index, in increasing order.
+    ///
+    /// Both possible failures are reported through the single error type `E` of `f`, which must be
+    /// convertible from [`CapacityError`]. This allows propagating them with `?` into an
+    /// application error type.
+    ///
+    /// # Errors
+    ///
+    /// Returns the conversion of [`CapacityError`] into `E` if `length` exceeds the capacity,
+    /// without calling `f`.
+    ///
+    /// Returns the error returned by `f` as soon as `f` fails. In this case, the elements already
+    /// created are dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::num::ParseIntError;
+    ///
+    /// use static_vector::{CapacityError, Vec};
+    ///
+    /// #[derive(Debug)]
+    /// enum AppError {
+    ///     VectorCapacityError(CapacityError),
+    ///     ParseError(ParseIntError),
+    /// }
+    ///
+    /// impl From<CapacityError> for AppError {
+    ///     fn from(err: CapacityError) -> Self {
+    ///         AppError::VectorCapacityError(err)
+    ///     }
+    /// }
+    ///
+    /// fn parse(fields: &[&str]) -> Result<Vec<u8, 4>, AppError> {
+    ///     Vec::try_from_fn(fields.len(), |index| fields[index].parse().map_err(AppError::ParseError))
+    /// }
+    ///
+    /// assert_eq!(parse(&["1", "2", "3"]).unwrap().as_slice(), [1, 2, 3]);
+    /// assert!(matches!(parse(&["1", "x"]), Err(AppError::ParseError(_))));
+    /// assert!(matches!(parse(&["1"; 5]), Err(AppError::VectorCapacityError(_))));
+    /// ```
+    #[inline]
+    #[doc(alias("from_fn", "generate", "build"))]
+    pub fn try_from_fn<E: From<CapacityError>, F: FnMut(usize) -> Result<T, E>>(
+        length: usize,
+        mut f: F,
+    ) -> Result<Self, E> {
+        if length > CAPACITY {
+            return Err(CapacityError.into());
+        }
+
+        let mut vec = Self::new();
+        for index in 0..length {
+            vec.push_unchecked(f(index)?);
+        }
+
+        Ok(vec)
+    }
+
+    /// Creates a vector from the first `CAPACITY` elements of `iter`. The remaining elements are
+    /// discarded, not reported as an error: they are never pulled from the iterator, which is then
+    /// dropped along with any elements it still owns.
+    ///
+    /// Suitable for infinite iterators.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let vec = Vec::<u32, 4>::from_iter_truncating((1..).map(|n| n * n));
+    /// assert_eq!(vec.as_slice(), [1, 4, 9, 16]);
+    ///
+    /// let vec = Vec::<u32, 4>::from_iter_truncating([1, 2]);
+    /// assert_eq!(vec.as_slice(), [1, 2]);
+    /// ```
+    #[inline]
+    #[doc(alias("collect", "take", "sample"))]
+    pub fn from_iter_truncating<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        for value in iter.into_iter().take(CAPACITY) {
+            vec.push_unchecked(value);
+        }
+        vec
+    }
+
+    /// Creates a vector from the elements of `iter` until the vector is full or the iterator is
+    /// exhausted, and returns it together with the iterator. The iterator is positioned at the
+    /// first element that was not taken: no element is pulled once the vector is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let (first, rest) = Vec::<u32, 3>::collect_until_full(1..=8);
+    /// assert_eq!(first.as_slice(), [1, 2, 3]);
+    ///
+    /// let (second, mut rest) = Vec::<u32, 3>::collect_until_full(rest);
+    /// assert_eq!(second.as_slice(), [4, 5, 6]);
+    /// assert_eq!(rest.next(), Some(7));
+    /// ```
+    #[inline]
+    #[doc(alias("batch", "take", "try_collect"))]
+    pub fn collect_until_full<I: IntoIterator<Item = T>>(iter: I) -> (Self, I::IntoIter) {
+        let mut vec = Self::new();
+        let remaining = vec.push_iter_saturating(iter);
+        (vec, remaining)
+    }
+
+    /// Appends elements from `iter` until the vector is full or the iterator is exhausted, and
+    /// returns the iterator. The iterator is positioned at the first element that was not pushed:
+    /// no element is pulled once the vector is full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::Vec;
+    ///
+    /// let mut first = Vec::<u32, 4>::new();
+    /// first.push(0).unwrap();
+    /// let rest = first.push_iter_saturating(1..=5);
+    /// assert_eq!(first.as_slice(), [0, 1, 2, 3]);
+    ///
+    /// let mut second = Vec::<u32, 4>::new();
+    /// let mut rest = second.push_iter_saturating(rest);
+    /// assert_eq!(second.as_slice(), [4, 5]);
+    /// assert_eq!(rest.next(), None);
+    /// ```
+    #[inline]
+    #[doc(alias("extend", "fill", "split"))]
+    pub fn push_iter_saturating<I: IntoIterator<Item = T>>(&mut self, iter: I) -> I::IntoIter {
+        let mut remaining = iter.into_iter();
+        while !self.is_full() {
+            match remaining.next() {
+                Some(value) => self.push_unchecked(value),
+                None => break,
+            }
+        }
+        remaining
+    }
+
+    /// Removes the trailing elements for which the predicate returns true, stopping at the last
+    /// element for which it returns false.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![0, 1, 0, 2, 0, 0];
+    ///
+    /// vec.truncate_while(|n| *n == 0);
+    /// assert_eq!(vec.as_slice(), [0, 1, 0, 2]);
+    /// ```
+    #[inline]
+    #[doc(alias("trim_end", "rtrim", "pop_while"))]
+    pub fn truncate_while<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        let new_length =
+            self.as_slice().iter().rposition(|element| !predicate(element)).map_or(0, |i| i + 1);
+        self.truncate_unchecked(new_length);
+    }
+
+    /// Shortens the vector to the longest prefix it has in common with `other`, dropping the
+    /// elements after it. Does nothing if the whole vector is a prefix of `other`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut state = vec![1, 2, 3, 4, 5];
+    ///
+    /// state.truncate_to_slice(&[1, 2, 9, 4, 5, 6]);
+    /// assert_eq!(state.as_slice(), [1, 2]);
+    ///
+    /// state.truncate_to_slice(&[1, 2, 3]);
+    /// assert_eq!(state.as_slice(), [1, 2]);
+    /// ```
+    #[inline]
+    #[doc(alias("common_prefix", "diff", "truncate"))]
+    pub fn truncate_to_slice(&mut self, other: &[T])
+    where
+        T: PartialEq,
+    {
+        self.truncate_unchecked(self.common_prefix_len(other));
+    }
+
+    /// Removes the leading elements for which the predicate returns true, stopping at the first
+    /// element for which it returns false, and shifts the remaining elements to the front.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![0, 0, 1, 0, 2, 0];
+    ///
+    /// vec.drain_front_while(|n| *n == 0);
+    /// assert_eq!(vec.as_slice(), [1, 0, 2, 0]);
+    /// ```
+    #[inline]
+    #[doc(alias("trim_start", "ltrim", "skip_while"))]
+    pub fn drain_front_while<F: FnMut(&T) -> bool>(&mut self, mut predicate: F) {
+        let removed = self.iter().position(|element| !predicate(element)).unwrap_or(self.length);
+        self.as_mut_slice().rotate_left(removed);
+        self.truncate_unchecked(self.length - removed);
+    }
+
+    /// Splits the vector's elements into a slice of `N`-element arrays, starting at the beginning,
+    /// and a remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let (chunks, remainder) = vec.as_chunks::<2>();
+    /// assert_eq!(chunks, [[1, 2], [3, 4]]);
+    /// assert_eq!(remainder, [5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let chunks = self.length.div_euclid(N);
+        let (head, remainder) = self.as_slice().split_at(chunks * N);
+
+        // SAFETY:
+        // - The chunked part holds exactly `chunks * N` initialized elements.
+        // - `[T; N]` has the same layout as `N` consecutive `T` values.
+        let multiple = unsafe { slice::from_raw_parts(head.as_ptr().cast::<[T; N]>(), chunks) };
+        (multiple, remainder)
+    }
+
+    /// Splits the vector's elements into a remainder slice with length strictly less than `N`
+    /// and a slice of `N`-element arrays, ending at the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let (remainder, chunks) = vec.as_rchunks::<2>();
+    /// assert_eq!(remainder, [1]);
+    /// assert_eq!(chunks, [[2, 3], [4, 5]]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_rchunks<const N: usize>(&self) -> (&[T], &[[T; N]]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let chunks = self.length.div_euclid(N);
+        let (remainder, tail) = self.as_slice().split_at(self.length - chunks * N);
+
+        // SAFETY:
+        // - The chunked part holds exactly `chunks * N` initialized elements.
+        // - `[T; N]` has the same layout as `N` consecutive `T` values.
+        let multiple = unsafe { slice::from_raw_parts(tail.as_ptr().cast::<[T; N]>(), chunks) };
+        (remainder, multiple)
+    }
+
+    /// Splits the vector's elements into a mutable slice of `N`-element arrays, starting at the
+    /// beginning, and a mutable remainder slice with length strictly less than `N`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let (chunks, remainder) = vec.as_chunks_mut::<2>();
+    /// chunks[1] = [30, 40];
+    /// remainder[0] = 50;
+    /// assert_eq!(vec.as_slice(), [1, 2, 30, 40, 50]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_chunks_mut<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let chunks = self.length.div_euclid(N);
+        let (head, remainder) = self.as_mut_slice().split_at_mut(chunks * N);
+
+        // SAFETY:
+        // - The chunked part holds exactly `chunks * N` initialized elements.
+        // - `[T; N]` has the same layout as `N` consecutive `T` values.
+        let multiple =
+            unsafe { slice::from_raw_parts_mut(head.as_mut_ptr().cast::<[T; N]>(), chunks) };
+        (multiple, remainder)
+    }
+
+    /// Splits the vector's elements into a mutable remainder slice with length strictly less than
+    /// `N` and a mutable slice of `N`-element arrays, ending at the end of the vector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// let (remainder, chunks) = vec.as_rchunks_mut::<2>();
+    /// remainder[0] = 10;
+    /// chunks[0] = [20, 30];
+    /// assert_eq!(vec.as_slice(), [10, 20, 30, 4, 5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_rchunks_mut<const N: usize>(&mut self) -> (&mut [T], &mut [[T; N]]) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let chunks = self.length.div_euclid(N);
+        let split = self.length - chunks * N;
+        let (remainder, tail) = self.as_mut_slice().split_at_mut(split);
+
+        // SAFETY:
+        // - The chunked part holds exactly `chunks * N` initialized elements.
+        // - `[T; N]` has the same layout as `N` consecutive `T` values.
+        let multiple =
+            unsafe { slice::from_raw_parts_mut(tail.as_mut_ptr().cast::<[T; N]>(), chunks) };
+        (remainder, multiple)
+    }
+
+    /// Splits the vector's elements into a mutable slice of `N`-element arrays and a mutable
+    /// remainder slice of `len() % N` elements. Same as [`Vec::as_chunks_mut()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut pixels = vec![u8; 10; 10];
+    ///
+    /// let (rgba, rest) = pixels.chunks_mut_array::<4>();
+    /// for pixel in rgba {
+    ///     *pixel = [255, 0, 0, 255];
+    /// }
+    /// assert_eq!(rest.len(), 2);
+    /// assert_eq!(pixels.as_slice(), [255, 0, 0, 255, 255, 0, 0, 255, 0, 0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("split_chunks_mut", "pixels"))]
+    pub const fn chunks_mut_array<const N: usize>(&mut self) -> (&mut [[T; N]], &mut [T]) {
+        self.as_chunks_mut()
+    }
+
+    /// Returns an array reference to the first `N` elements of the vector and a slice with the
+    /// remaining elements, or [`None`] if the vector has less than `N` elements.
+    ///
+    /// See [`slice::split_first_chunk()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let packet = vec![0xCA, 0xFE, 1, 2, 3];
+    ///
+    /// let (header, payload) = packet.split_first_chunk::<2>().unwrap();
+    /// assert_eq!(header, &[0xCA, 0xFE]);
+    /// assert_eq!(payload, [1, 2, 3]);
+    /// assert!(packet.split_first_chunk::<6>().is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("header", "peel"))]
+    pub const fn split_first_chunk<const N: usize>(&self) -> Option<(&[T; N], &[T])> {
+        self.as_slice().split_first_chunk()
+    }
+
+    /// Returns a mutable array reference to the first `N` elements of the vector and a mutable
+    /// slice with the remaining elements, or [`None`] if the vector has less than `N` elements.
+    ///
+    /// See [`slice::split_first_chunk_mut()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut packet = vec![0, 0, 1, 2, 3];
+    ///
+    /// if let Some((header, payload)) = packet.split_first_chunk_mut::<2>() {
+    ///     *header = [0xCA, 0xFE];
+    ///     payload[0] = 9;
+    /// }
+    /// assert_eq!(packet.as_slice(), [0xCA, 0xFE, 9, 2, 3]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("header", "peel"))]
+    pub const fn split_first_chunk_mut<const N: usize>(
+        &mut self,
+    ) -> Option<(&mut [T; N], &mut [T])> {
+        self.as_mut_slice().split_first_chunk_mut()
+    }
+
+    /// Returns a slice with the leading elements of the vector and an array reference to the last
+    /// `N` elements, or [`None`] if the vector has less than `N` elements.
+    ///
+    /// See [`slice::split_last_chunk()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let frame = vec![1, 2, 3, 0xAB, 0xCD];
+    ///
+    /// let (payload, trailer) = frame.split_last_chunk::<2>().unwrap();
+    /// assert_eq!(payload, [1, 2, 3]);
+    /// assert_eq!(trailer, &[0xAB, 0xCD]);
+    /// assert!(frame.split_last_chunk::<6>().is_none());
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("trailer", "footer"))]
+    pub const fn split_last_chunk<const N: usize>(&self) -> Option<(&[T], &[T; N])> {
+        self.as_slice().split_last_chunk()
+    }
+
+    /// Returns a mutable slice with the leading elements of the vector and a mutable array
+    /// reference to the last `N` elements, or [`None`] if the vector has less than `N` elements.
+    ///
+    /// See [`slice::split_last_chunk_mut()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut frame = vec![1, 2, 3, 0, 0];
+    ///
+    /// if let Some((payload, trailer)) = frame.split_last_chunk_mut::<2>() {
+    ///     payload[0] = 9;
+    ///     *trailer = [0xAB, 0xCD];
+    /// }
+    /// assert_eq!(frame.as_slice(), [9, 2, 3, 0xAB, 0xCD]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("trailer", "footer"))]
+    pub const fn split_last_chunk_mut<const N: usize>(
+        &mut self,
+    ) -> Option<(&mut [T], &mut [T; N])> {
+        self.as_mut_slice().split_last_chunk_mut()
+    }
+
+    /// Returns an array reference to the last `N` elements of the vector, or [`None`] if the
+    /// vector has less than `N` elements.
+    ///
+    /// See [`slice::last_chunk()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let frame = vec![1, 2, 3, 0xAB, 0xCD];
+    ///
+    /// assert_eq!(frame.last_chunk::<2>(), Some(&[0xAB, 0xCD]));
+    /// assert_eq!(frame.last_chunk::<6>(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("trailer", "footer"))]
+    pub const fn last_chunk<const N: usize>(&self) -> Option<&[T; N]> {
+        self.as_slice().last_chunk()
+    }
+
+    /// Returns a mutable array reference to the last `N` elements of the vector, or [`None`] if
+    /// the vector has less than `N` elements.
+    ///
+    /// See [`slice::last_chunk_mut()`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut frame = vec![1, 2, 3, 0, 0];
+    ///
+    /// if let Some(crc) = frame.last_chunk_mut::<2>() {
+    ///     *crc = [0xAB, 0xCD];
+    /// }
+    /// assert_eq!(frame.as_slice(), [1, 2, 3, 0xAB, 0xCD]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("trailer", "footer"))]
+    pub const fn last_chunk_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
+        self.as_mut_slice().last_chunk_mut()
+    }
+
+    /// Returns a slice of the first `n` elements of the vector, or of all elements if the vector
+    /// has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(vec.head(2), [1, 2]);
+    /// assert_eq!(vec.head(10), [1, 2, 3, 4, 5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("prefix", "take_first", "first_n"))]
+    pub const fn head(&self, n: usize) -> &[T] {
+        let mid = if n < self.length { n } else { self.length };
+        self.as_slice().split_at(mid).0
+    }
+
+    /// Returns a mutable slice of the first `n` elements of the vector, or of all elements if the
+    /// vector has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// vec.head_mut(2).fill(0);
+    /// assert_eq!(vec.as_slice(), [0, 0, 3, 4, 5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("prefix", "take_first", "first_n"))]
+    pub const fn head_mut(&mut self, n: usize) -> &mut [T] {
+        let mid = if n < self.length { n } else { self.length };
+        self.as_mut_slice().split_at_mut(mid).0
+    }
+
+    /// Returns a slice of the last `n` elements of the vector, or of all elements if the vector
+    /// has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// assert_eq!(vec.tail(2), [4, 5]);
+    /// assert_eq!(vec.tail(10), [1, 2, 3, 4, 5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("suffix", "take_last", "last_n"))]
+    pub const fn tail(&self, n: usize) -> &[T] {
+        let mid = self.length.saturating_sub(n);
+        self.as_slice().split_at(mid).1
+    }
+
+    /// Returns a mutable slice of the last `n` elements of the vector, or of all elements if the
+    /// vector has less than `n` elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5];
+    ///
+    /// vec.tail_mut(2).fill(0);
+    /// assert_eq!(vec.as_slice(), [1, 2, 3, 0, 0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[doc(alias("suffix", "take_last", "last_n"))]
+    pub const fn tail_mut(&mut self, n: usize) -> &mut [T] {
+        let mid = self.length.saturating_sub(n);
+        self.as_mut_slice().split_at_mut(mid).1
+    }
+
+    /// Returns an iterator over the first element and every `step`-th element after it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let vec = vec![0, 1, 2, 3, 4, 5, 6];
+    ///
+    /// let samples = vec.iter_step_by(3);
+    /// assert_eq!(samples.len(), 3);
+    /// assert!(samples.eq([&0, &3, &6]));
+    /// ```
+    #[inline]
+    #[doc(alias("step_by", "downsample", "stride"))]
+    pub fn iter_step_by(&self, step: usize) -> impl ExactSizeIterator<Item = &T> {
+        self.as_slice().iter().step_by(step)
+    }
+
+    /// Overwrites the elements of the vector with clones of the elements of `src`. Each existing
+    /// element is replaced by assignment, so the old element is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `src` is not equal to the length of the vector.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3];
+    ///
+    /// vec.clone_from_slice(&[4, 5, 6]);
+    /// assert_eq!(vec.as_slice(), [4, 5, 6]);
+    /// ```
+    #[inline]
+    #[doc(alias("overwrite", "refresh", "assign"))]
+    pub fn clone_from_slice(&mut self, src: &[T])
+    where
+        T: Clone,
+    {
+        assert!(
+            src.len() == self.length,
+            "source slice length (is {}) should be == len (is {})",
+            src.len(),
+            self.length
+        );
+        self.as_mut_slice().clone_from_slice(src);
+    }
+
+    /// Replaces the elements of `dst` with clones of the elements of the vector, reusing the
+    /// storage of `dst`. The old elements of `dst` are dropped before any element is cloned.
+    ///
+    /// [`Clone::clone_from()`] is implemented in terms of this method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::{Vec, vec};
+    ///
+    /// let template = vec![4; 1, 2, 3];
+    /// let mut scratch = Vec::<i32, 4>::new();
+    /// scratch.push(9).unwrap();
+    ///
+    /// template.clone_into(&mut scratch);
+    /// assert_eq!(scratch.as_slice(), [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[doc(alias("clone_from", "copy_to", "assign"))]
+    pub fn clone_into(&self, dst: &mut Self)
+    where
+        T: Clone,
+    {
+        dst.clear();
+        for value in self {
+            dst.push_unchecked(value.clone());
+        }
+    }
+
+    /// Removes every element equal to `value`, preserving the order of the remaining elements,
+    /// and returns the number of removed elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![0, 1, 0, 0, 2, 0];
+    ///
+    /// assert_eq!(vec.remove_all(&0), 4);
+    /// assert_eq!(vec.as_slice(), [1, 2]);
+    /// ```
+    #[inline]
+    #[doc(alias("compact", "remove_item", "erase"))]
+    pub fn remove_all(&mut self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        let length = self.length;
+        self.retain_core(|_, element| ControlFlow::Continue(element != value));
+        length - self.length
+    }
+
+    /// Retains only the elements for which `f` returns `ControlFlow::Continue(true)`, preserving
+    /// their order, and stops early when `f` returns `ControlFlow::Break(())`. `f` is given the
+    /// original index of each element and can modify it.
+    ///
+    /// When `f` breaks, the element it was given is kept, and so are all the elements after it,
+    /// without `f` being called for them.
+    ///
+    /// If `f` panics, the elements that were not visited yet are kept after the ones retained so
+    /// far, so no element is leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::ops::ControlFlow;
+    ///
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 10, 5, 6];
+    ///
+    /// // Drop odd numbers, double even numbers, stop at the first number over the threshold.
+    /// vec.retain_mut_until(|_, n| {
+    ///     if *n > 9 {
+    ///         return ControlFlow::Break(());
+    ///     }
+    ///     *n *= 2;
+    ///     ControlFlow::Continue(*n % 4 == 0)
+    /// });
+    /// assert_eq!(vec.as_slice(), [4, 8, 10, 5, 6]);
+    /// ```
+    #[doc(alias("filter", "retain_mut", "take_while"))]
+    pub fn retain_mut_until<F: FnMut(usize, &mut T) -> ControlFlow<(), bool>>(&mut self, f: F) {
+        self.retain_core(f);
+    }
+
+    /// Returns an iterator that removes the elements for which `f` returns `true` and yields them
+    /// one by one, in order. The elements for which `f` returns `false` are kept, preserving their
+    /// order. `f` can modify the elements it is given.
+    ///
+    /// Elements are only visited as the iterator is advanced. If the iterator is dropped before
+    /// being fully consumed, the remaining elements are still visited: the matching ones are
+    /// dropped, the others are kept.
+    ///
+    /// If `f` panics, the elements that were not visited yet are kept after the ones retained so
+    /// far, so no element is leaked or dropped twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let mut evens = vec.drain_where(|n| *n % 2 == 0);
+    /// assert_eq!(evens.next(), Some(2));
+    /// drop(evens);
+    ///
+    /// assert_eq!(vec.as_slice(), [1, 3, 5, 7]);
+    /// ```
+    #[inline]
+    #[doc(alias("extract_if", "drain_filter", "retain"))]
+    pub fn drain_where<F: FnMut(&mut T) -> bool>(&mut self, f: F) -> impl Iterator<Item = T> {
+        let length = self.length;
+        self.length = 0;
+        DrainWhere {
+            guard: RetainGuard { vec: self, length, read: 0, write: 0 },
+            predicate: f,
+            in_predicate: false,
+        }
+    }
+
+    /// Clones the elements in the given `range` of the vector and appends them to the end.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CapacityError`] if the new length would exceed the capacity. In this case, the
+    /// vector is not modified.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds of the vector's length or if its start is greater than
+    /// its end.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut vec = vec![8; 1, 2, 3, 4];
+    ///
+    /// vec.extend_from_within(..2).unwrap();
+    /// assert_eq!(vec.as_slice(), [1, 2, 3, 4, 1, 2]);
+    ///
+    /// assert!(vec.extend_from_within(..).is_err());
+    /// ```
+    #[inline]
+    #[doc(alias("duplicate", "repeat", "wrap_around"))]
+    pub fn extend_from_within<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Result<(), CapacityError>
+    where
+        T: Clone,
+    {
+        let bounds = (range.start_bound().cloned(), range.end_bound().cloned());
+        let count = self.as_slice()[bounds].len();
+        if self.length + count > CAPACITY {
+            return Err(CapacityError);
+        }
+
+        for index in 0..count {
+            let value = self.as_slice()[bounds][index].clone();
+            self.push_unchecked(value);
+        }
+
+        Ok(())
+    }
+
+    /// Fills the vector up to its capacity by repeatedly appending clones of its current elements,
+    /// in order. If the capacity is not a multiple of the length, the last repetition is partial:
+    /// it contains only the first elements of the original sequence that fit.
+    ///
+    /// Does nothing if the vector is empty or full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use static_vector::vec;
+    ///
+    /// let mut pattern = vec![8; 1, 2, 3];
+    ///
+    /// pattern.repeat_fill();
+    /// assert_eq!(pattern.as_slice(), [1, 2, 3, 1, 2, 3, 1, 2]);
+    /// ```
+    #[inline]
+    #[doc(alias("tile", "cycle", "repeat"))]
+    pub fn repeat_fill(&mut self)
+    where
+        T: Clone,
+    {
+        let period = self.length;
+        if period == 0 {
+            return;
+        }
+
+        while !self.is_full() {
+            let value = self.as_slice()[self.length - period].clone();
+            self.push_unchecked(value);
+        }
+    }
+
+    /// Adds the given `value` to the end of the vector without checking bounds.
+    /// For internal and controlled use only.
+    fn push_unchecked(&mut self, value: T) {
+        debug_assert!(!self.is_full(), "cannot push to full vector");
+        self.data[self.length].write(value);
+        self.length += 1;
+    }
+
+    /// Shortens the vector to `new_length`, dropping the elements after it. The length is updated
+    /// before dropping, so a panicking destructor cannot cause an element to be dropped twice.
+    /// For internal and controlled use only.
+    fn truncate_unchecked(&mut self, new_length: usize) {
+        debug_assert!(new_length <= self.length, "cannot truncate to a larger length");
+        let old_length = self.length;
+        self.length = new_length;
+        self.drop_range(new_length, old_length);
+    }
+
+    /// Moves the elements starting at index `from` to the end of `other`, without checking the
+    /// capacity of `other`. For internal and controlled use only.
+    fn move_tail_unchecked<const OTHER_CAPACITY: usize>(
+        &mut self,
+        from: usize,
+        other: &mut Vec<T, OTHER_CAPACITY>,
+    ) {
+        let count = self.length - from;
+        debug_assert!(other.length + count <= OTHER_CAPACITY, "cannot move to full vector");
+
+        let src = self.data[from..].as_ptr();
+        let dst = other.data[other.length..].as_mut_ptr();
+        self.length = from;
+        other.length += count;
+
+        // SAFETY:
+        // - The `count` elements starting at `from` are initialized.
+        // - `other` has room for `count` more elements after its previous length.
+        // - The two vectors are distinct, so the regions do not overlap.
+        // - The lengths are already updated, so each element is owned by exactly one vector.
+        unsafe {
+            ptr::copy_nonoverlapping(src, dst, count);
+        }
+    }
+
+    /// Keeps only the elements for which `keep` returns `Continue(true)`, preserving their order.
+    /// `keep` is given the original index of each element. Removed elements are dropped as they
+    /// are visited, from front to back.
+    ///
+    /// If `keep` returns `Break`, filtering stops and the element it was given, together with all
+    /// the elements that follow, is kept.
+    ///
+    /// If `keep` panics, the elements that were not yet visited are kept, so no element is leaked
+    /// or dropped twice.
+    fn retain_core<F: FnMut(usize, &mut T) -> ControlFlow<(), bool>>(&mut self, mut keep: F) {
+        let length = self.length;
+        self.length = 0;
+        let mut guard = RetainGuard { vec: self, length, read: 0, write: 0 };
+
+        while guard.read < guard.length {
+            let index = guard.read;
+
+            // SAFETY: The element at `index` has been initialized and was neither moved nor
+            // dropped.
+            let element = unsafe { guard.vec.data[index].assume_init_mut() };
+
+            match keep(index, element) {
+                ControlFlow::Continue(true) => {
+                    guard.vec.data.swap(index, guard.write);
+                    guard.write += 1;
+                    guard.read += 1;
+                },
+                ControlFlow::Continue(false) => {
+                    guard.read += 1;
+
+                    // SAFETY: The element at `index` has been initialized and is no longer
+                    // considered part of the vector, so it is dropped only once.
+                    unsafe {
+                        guard.vec.data[index].assume_init_drop();
+                    }
+                },
+                ControlFlow::Break(()) => break,
+            }
+        }
+    }
+
+    /// Returns the index of the element kept when scanning from front to back and replacing the
+    /// current element only when `replaces(element, current)` returns true, or [`None`] if the
+    /// vector is empty.
+    fn position_by<F: FnMut(&T, &T) -> bool>(&self, mut replaces: F) -> Option<usize> {
+        let mut best: Option<(usize, &T)> = None;
+        for (index, element) in self.iter().enumerate() {
+            if best.is_none_or(|(_, current)| replaces(element, current)) {
+                best = Some((index, element));
+            }
+        }
+        best.map(|(index, _)| index)
+    }
+
+    /// Panics if `index` is not a valid position to insert an element at.
+    fn assert_insertion_index(&self, index: usize) {
+        assert!(
+            index <= self.length,
+            "insertion index (is {index}) should be <= len (is {})",
+            self.length
+        );
+    }
+
+    /// Drops all elements in given range. Needed when elements are considered to be going out of
+    /// scope. E.g.: when the vector is going out of scope, when methods such as
+    /// [`Vec::clear()`] and [`Vec::set_len()`] are called.
+    fn drop_range(&mut self, from: usize, to: usize) {
+        for i in from..to {
+            // SAFETY:
+            // - `i` is within bounds of `self.data`.
+            // - The element at `i` has been initialized.
+            unsafe {
+                self.data[i].assume_init_drop();
+            }
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for Vec<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.drop_range(0, self.length);
+    }
+}
+
+impl<T: Clone, const CAPACITY: usize> Clone for Vec<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut vec = Self::new();
+        for value in self {
+            vec.push_unchecked(value.clone());
+        }
+        vec
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        source.clone_into(self);
+    }
+}
+
+/// Moves the elements of an array into a new vector, which can have a larger capacity than the
+/// array length.
+///
+/// Fails with [`CapacityError`] if the array has more than `CAPACITY` elements.
+///
+/// # Example
+///
+/// ```rust
+/// use static_vector::Vec;
+///
+/// let vec = Vec::<i32, 8>::try_from([1, 2, 3]).unwrap();
+/// assert_eq!(vec.as_slice(), [1, 2, 3]);
+///
+/// assert!(Vec::<i32, 2>::try_from([1, 2, 3]).is_err());
+/// ```
+impl<T, const N: usize, const CAPACITY: usize> TryFrom<[T; N]> for Vec<T, CAPACITY> {
+    type Error = CapacityError;
+
+    fn try_from(array: [T; N]) -> Result<Self, Self::Error> {
+        let mut vec = Self::new();
+        vec.push_array(array)?;
+        Ok(vec)
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize, const OTHER_CAPACITY: usize>
+    PartialEq<Vec<T, OTHER_CAPACITY>> for Vec<T, CAPACITY>
+{
+    fn eq(&self, other: &Vec<T, OTHER_CAPACITY>) -> bool {
+        self.len() == other.len() && self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for Vec<T, CAPACITY> {}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq, const CAPACITY: usize> PartialEq<AllocVec<T>> for Vec<T, CAPACITY> {
+    fn eq(&self, other: &AllocVec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq, const CAPACITY: usize> PartialEq<Vec<T, CAPACITY>> for AllocVec<T> {
+    fn eq(&self, other: &Vec<T, CAPACITY>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd, const CAPACITY: usize, const OTHER_CAPACITY: usize>
+    PartialOrd<Vec<T, OTHER_CAPACITY>> for Vec<T, CAPACITY>
+{
+    fn partial_cmp(&self, other: &Vec<T, OTHER_CAPACITY>) -> Option<cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const CAPACITY: usize> Hash for Vec<T, CAPACITY> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T: fmt::Debug, const CAPACITY: usize> fmt::Debug for Vec<T, CAPACITY> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+/// Restores the state of a [`Vec`] filtered in place, when filtering ends or when the predicate
+/// panics. The elements in `read..length` were not visited yet and are moved right after the kept
+/// elements in `0..write`.
+struct RetainGuard<'a, T, const CAPACITY: usize> {
+    vec: &'a mut Vec<T, CAPACITY>,
+    length: usize,
+    read: usize,
+    write: usize,
+}
+
+impl<T, const CAPACITY: usize> Drop for RetainGuard<'_, T, CAPACITY> {
+    fn drop(&mut self) {
+        if self.read < self.length {
+            self.vec.data[self.write..self.length].rotate_left(self.read - self.write);
+        }
+        self.vec.length = self.write + self.length - self.read;
+    }
+}
+
+/// Iterator moving elements out of a [`Vec`]. The length of the vector is set to zero before
+/// draining, so the elements in `index..end` are owned by the iterator and dropped with it.
+struct Drain<'a, T, const CAPACITY: usize> {
+    vec: &'a mut Vec<T, CAPACITY>,
+    index: usize,
+    end: usize,
+}
+
+impl<T, const CAPACITY: usize> Iterator for Drain<'_, T, CAPACITY> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            // SAFETY:
+            // - `self.index` is within bounds of `self.vec.data`.
+            // - The element at `self.index` has been initialized and is read only once.
+            let value = unsafe { self.vec.data[self.index].assume_init_read() };
+            self.index += 1;
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for Drain<'_, T, CAPACITY> {
+    fn drop(&mut self) {
+        self.vec.drop_range(self.index, self.end);
+    }
+}
+
+/// Iterator moving out of a [`Vec`] the elements that match a predicate, compacting the kept ones
+/// through a [`RetainGuard`].
+struct DrainWhere<'a, T, const CAPACITY: usize, F: FnMut(&mut T) -> bool> {
+    guard: RetainGuard<'a, T, CAPACITY>,
+    predicate: F,
+    /// Set while the predicate runs, so a panicking predicate is not called again on drop.
+    in_predicate: bool,
+}
+
+impl<T, const CAPACITY: usize, F: FnMut(&mut T) -> bool> Iterator
+    for DrainWhere<'_, T, CAPACITY, F>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.guard.read < self.guard.length {
+            let index = self.guard.read;
+
+            // SAFETY: The element at `index` has been initialized and was neither moved nor
+            // dropped.
+            let element = unsafe { self.guard.vec.data[index].assume_init_mut() };
+
+            self.in_predicate = true;
+            let remove = (self.predicate)(element);
+            self.in_predicate = false;
+
+            self.guard.read += 1;
+
+            if remove {
+                // SAFETY: The element at `index` has been initialized and is no longer considered
+                // part of the vector, so it is read only once.
+                return Some(unsafe { self.guard.vec.data[index].assume_init_read() });
+            }
+
+            self.guard.vec.data.swap(index, self.guard.write);
+            self.guard.write += 1;
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.guard.length - self.guard.read))
+    }
+}
+
+impl<T, const CAPACITY: usize, F: FnMut(&mut T) -> bool> Drop for DrainWhere<'_, T, CAPACITY, F> {
+    fn drop(&mut self) {
+        if !self.in_predicate {
+            self.for_each(drop);
+        }
+    }
+}
+
+/// Immutable iterator over a [`Vec`].
+///
+/// Created by calling [`Vec::iter()`].
+#[must_use = "must consume iterator"]
+pub struct Iter<'a, T> {
+    data: &'a [MaybeUninit<T>],
+    size: usize,
+    index: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Creates immutable iterator.
+    #[inline]
+    pub const fn new(data: &'a [MaybeUninit<T>], size: usize) -> Self {
+        Self { data, size, index: 0 }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.size {
+            None
+        } else {
+            // SAFETY:
+            // - `self.index` is within bounds of `self.data`.
+            // - The element at `self.index` has been initialized.
+            let value = unsafe { &*self.data[self.index].as_ptr() };
+            self.index += 1;
+            Some(value)
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.size {
+            None
+        } else {
+            self.size -= 1;
+
+            // SAFETY:
+            // - `self.size` is within bounds of `self.data`.
+            // - The element at `self.size` has been initialized.
+            Some(unsafe { &*self.data[self.size].as_ptr() })
+        }
+    }
+}
+
+impl<'a, T: 'a, const CAPACITY: usize> IntoIterator for &'a Vec<T, CAPACITY> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Mutable iterator over a [`Vec`].
+///
+/// Created by calling [`Vec::iter_mut()`].
+#[must_use = "must consume iterator"]
+pub struct IterMut<'a, T> {
+    data: &'a mut [MaybeUninit<T>],
+    size: usize,
+    index: usize,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    /// Creates mutable iterator.
+    #[inline]
+    pub const fn new(data: &'a mut [MaybeUninit<T>], size: usize) -> Self {
+        Self { data, size, index: 0 }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.size {
+            None
+        } else {
+            // SAFETY:
+            // - `self.index` is within bounds of `self.data`.
+            // - The element at `self.index` has been initialized.
+            // - The element at `self.index` is not yielded again, by `next` or `next_back`.
+            let value = unsafe { &mut *self.data[self.index].as_mut_ptr() };
+
+            self.index += 1;
+            Some(value)
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.size {
+            None
+        } else {
+            self.size -= 1;
+
+            // SAFETY:
+            // - `self.size` is within bounds of `self.data`.
+            // - The element at `self.size` has been initialized.
+            // - The element at `self.size` is not yielded again, by `next` or `next_back`.
+            Some(unsafe { &mut *self.data[self.size].as_mut_ptr() })
+        }
+    }
+}
+
+impl<'a, T: 'a, const CAPACITY: usize> IntoIterator for &'a mut Vec<T, CAPACITY> {
+    type IntoIter = IterMut<'a, T>;
+    type Item = &'a mut T;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Owning iterator over a [`Vec`], moving the elements out of it.
+///
+/// Created by calling `into_iter()` on a [`Vec`]. Elements that are not consumed are dropped with
+/// the iterator.
+#[must_use = "must consume iterator"]
+pub struct IntoIter<T, const CAPACITY: usize> {
+    vec: Vec<T, CAPACITY>,
+    index: usize,
+    end: usize,
+}
+
+impl<T, const CAPACITY: usize> Iterator for IntoIter<T, CAPACITY> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            // SAFETY:
+            // - `self.index` is within bounds of `self.vec.data`.
+            // - The element at `self.index` has been initialized and is read only once.
+            let value = unsafe { self.vec.data[self.index].assume_init_read() };
+            self.index += 1;
+            Some(value)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const CAPACITY: usize> DoubleEndedIterator for IntoIter<T, CAPACITY> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+
+            // SAFETY:
+            // - `self.end` is within bounds of `self.vec.data`.
+            // - The element at `self.end` has been initialized and is read only once.
+            Some(unsafe { self.vec.data[self.end].assume_init_read() })
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> Drop for IntoIter<T, CAPACITY> {
+    fn drop(&mut self) {
+        self.vec.drop_range(self.index, self.end);
+    }
+}
+
+impl<T, const CAPACITY: usize> IntoIterator for Vec<T, CAPACITY> {
+    type IntoIter = IntoIter<T, CAPACITY>;
+    type Item = T;
+
+    /// Creates an iterator moving the elements out of the vector, from front to back or, with
+    /// [`DoubleEndedIterator`], from back to front.
+    fn into_iter(mut self) -> Self::IntoIter {
+        let end = self.length;
+        // The elements are owned by the iterator, which drops the ones not consumed.
+        self.length = 0;
+        IntoIter { vec: self, index: 0, end }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    extern crate alloc;
+    extern crate std;
+    use alloc::format;
+    use core::cell::RefCell;
+    use core::error::Error;
+    use std::{panic, thread_local};
+
+    fn assert_is_core_error<T: Error>() {}
+
+    fn assert_is_send_and_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn new() {
+        let mut vec = Vec::<Struct, 10>::new();
+        assert!(vec.is_empty());
+        assert!(!vec.is_full());
+        assert_eq!(vec.len(), 0);
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(vec.as_slice(), []);
+        assert_eq!(vec.as_mut_slice(), []);
+        assert_eq!(DEFAULTS.get(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "CAPACITY must be greater than 0")]
+    fn new_with_capacity_zero() {
+        let _ = Vec::<i32, 0>::new();
+    }
+
+    #[test]
+    fn default() {
+        let mut vec = Vec::<Struct, 10>::default();
+        assert!(vec.is_empty());
+        assert!(!vec.is_full());
+        assert_eq!(vec.len(), 0);
+        assert_eq!(vec.capacity(), 10);
+        assert_eq!(vec.as_slice(), []);
+        assert_eq!(vec.as_mut_slice(), []);
+        assert_eq!(DEFAULTS.get(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "CAPACITY must be greater than 0")]
+    fn default_with_capacity_zero() {
+        let _ = Vec::<i32, 0>::default();
+    }
+
+    #[test]
+    fn capacity() {
+        let mut vec = Vec::<i32, 3>::new();
+        assert_eq!(vec.capacity(), 3);
+
+        vec.set_len(2).unwrap();
+        assert_eq!(vec.capacity(), 3);
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.capacity(), 3);
+
+        vec.clear();
+        assert_eq!(vec.capacity(), 3);
+    }
+
+    #[test]
+    fn len() {
+        let mut vec = Vec::<i32, 3>::new();
+        assert_eq!(vec.len(), 0);
+
+        vec.set_len(2).unwrap();
+        assert_eq!(vec.len(), 2);
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.len(), 3);
+
+        vec.clear();
+        assert_eq!(vec.len(), 0);
+    }
+
+    #[test]
+    fn byte_len() {
+        let mut vec = Vec::<u32, 3>::new();
+        assert_eq!(vec.byte_len(), 0);
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.byte_len(), 4);
+
+        vec.extend_from_slice(&[2, 3]).unwrap();
+        assert_eq!(vec.byte_len(), 12);
+        assert_eq!(vec.byte_len(), size_of_val(vec.as_slice()));
+
+        let mut bytes = Vec::<u8, 3>::new();
+        bytes.extend_from_slice(&[1, 2]).unwrap();
+        assert_eq!(bytes.byte_len(), bytes.len());
+
+        let mut zst = Vec::<(), 3>::new();
+        zst.extend_from_slice(&[(), ()]).unwrap();
+        assert_eq!(zst.byte_len(), 0);
+    }
+
+    #[test]
+    fn as_bytes_mut() {
+        let mut vec = Vec::<u16, 4>::new();
+        assert!(vec.as_bytes_mut().is_empty());
+
+        vec.extend_from_slice(&[1, 2]).unwrap();
+        assert_eq!(vec.as_bytes_mut().len(), 4);
+
+        vec.as_bytes_mut()[2..].fill(u8::MAX);
+        assert_eq!(vec.as_slice(), [1, u16::MAX]);
+
+        vec.as_bytes_mut().fill(u8::MIN);
+        assert_eq!(vec.as_slice(), [0, 0]);
+
+        let mut floats = Vec::<f32, 2>::new();
+        floats.push(1.5).unwrap();
+        let mut source = Vec::<f32, 2>::new();
+        source.push(2.5).unwrap();
+        floats.as_bytes_mut().copy_from_slice(source.as_bytes_mut());
+        assert!(floats.iter().map(|f| f.to_bits()).eq(source.iter().map(|f| f.to_bits())));
+    }
+
+    #[test]
+    fn is_empty() {
+        let mut vec = Vec::<i32, 3>::new();
+        assert!(vec.is_empty());
+
+        vec.push(1).unwrap();
+        assert!(!vec.is_empty());
+
+        vec.set_len(2).unwrap();
+        assert!(!vec.is_empty());
+
+        vec.clear();
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn is_full() {
+        let mut vec = Vec::<i32, 3>::new();
+        assert!(!vec.is_full());
+
+        vec.push(1).unwrap();
+        assert!(!vec.is_full());
+
+        vec.set_len(3).unwrap();
+        assert!(vec.is_full());
+
+        vec.clear();
+        assert!(!vec.is_full());
+
+        vec.push(1).unwrap();
+        vec.push(1).unwrap();
+        vec.push(1).unwrap();
+        assert!(vec.is_full());
+    }
+
+    #[test]
+    fn ensure_capacity() {
+        let mut vec = Vec::<i32, 3>::new();
+        vec.ensure_capacity(0).unwrap();
+        vec.ensure_capacity(3).unwrap();
+        vec.ensure_capacity(4).unwrap_err();
+
+        vec.push(1).unwrap();
+        vec.ensure_capacity(2).unwrap();
+        vec.ensure_capacity(3).unwrap_err();
+        vec.ensure_capacity(usize::MAX).unwrap_err();
+
+        vec.push(2).unwrap();
+        vec.push(3).unwrap();
+        vec.ensure_capacity(0).unwrap();
+        vec.ensure_capacity(1).unwrap_err();
+    }
+
+    #[test]
+    fn push() {
+        let mut vec = Vec::<i32, 2>::new();
+        assert!(vec.push(1).is_ok());
+        assert!(vec.push(2).is_ok());
+
+        assert!(matches!(vec.push(3), Err(CapacityError)));
+        assert_eq!(format!("{}", vec.push(3).unwrap_err()), "vector needs larger capacity");
+        assert_is_core_error::<CapacityError>();
+
+        assert_eq!(vec.as_slice(), &[1, 2]);
+        assert!(vec.get(2).is_none());
+        assert!(vec.get(99).is_none());
+    }
+
+    #[test]
+    fn push_should_not_create_default_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+
+        vec.push(Struct { i: 0 }).unwrap();
+
+        assert_eq!(DEFAULTS.get(), 0);
+        assert_eq!(vec.as_slice(), &[Struct { i: 0 }]);
+    }
+
+    #[test]
+    fn push_should_not_clone_element() {
+        let mut vec = Vec::<Struct, 10>::new();
+
+        vec.push(Struct { i: 1 }).unwrap();
+        assert_eq!(CLONES.get(), 0);
+
+        vec.push(Struct { i: 2 }).unwrap();
+        vec.push(Struct { i: 3 }).unwrap();
+        assert_eq!(CLONES.get(), 0);
+
+        assert_eq!(vec.as_slice(), &[Struct { i: 1 }, Struct { i: 2 }, Struct { i: 3 }]);
+    }
+
+    #[test]
+    fn clear() {
+        let mut vec = Vec::<i32, 3>::new();
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.capacity(), 3);
+        assert!(!vec.is_empty());
+        assert!(vec.is_full());
+        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+
+        vec.clear();
+
+        assert_eq!(vec.len(), 0);
+        assert_eq!(vec.capacity(), 3);
+        assert!(vec.is_empty());
+        assert!(!vec.is_full());
+        assert_eq!(vec.as_slice(), &[]);
+    }
+
+    #[test]
+    fn clear_should_drop_all_allocated_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        assert_eq!(DROPS.get(), 0);
+
+        let s = Struct { i: 0 };
+        for _ in 1..=3 {
+            vec.push(s.clone()).unwrap();
+        }
+        assert_eq!(DROPS.get(), 0);
+
+        vec.clear();
+        assert_eq!(DROPS.get(), 3);
+
+        assert_eq!(CLONES.get(), 3); // the three clones before push
+        assert_eq!(DEFAULTS.get(), 0);
+    }
+
+    #[test]
+    fn take() {
+        let mut vec = Vec::<i32, 3>::new();
+        assert!(vec.take().is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        let taken = vec.take();
+        assert_eq!(taken.as_slice(), [1, 2, 3]);
+        assert_eq!(taken.capacity(), 3);
+        assert!(vec.is_empty());
+
+        vec.push(4).unwrap();
+        assert_eq!(vec.as_slice(), [4]);
+        assert_eq!(taken.as_slice(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn take_should_move_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let taken = vec.take();
+        assert!(taken.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(DROPS.get(), 0);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 0);
+
+        drop(taken);
+        assert_eq!(DROPS.get(), 3);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn set_len() {
+        let mut vec = Vec::<i32, 3>::new();
+
+        // New length less than capacity
+        assert!(vec.set_len(1).is_ok());
+        assert_eq!(vec.len(), 1);
+        assert!(!vec.is_empty());
+        assert!(!vec.is_full());
+        assert_eq!(vec.as_slice(), [0]);
+
+        // New length larger than capacity
+        assert!(matches!(vec.set_len(100), Err(CapacityError)));
+        assert_eq!(format!("{}", vec.set_len(100).unwrap_err()), "vector needs larger capacity");
+        assert_is_core_error::<CapacityError>();
+        assert_eq!(vec.len(), 1);
+        assert!(!vec.is_empty());
+        assert!(!vec.is_full());
+        assert_eq!(vec.as_slice(), [0]);
+
+        // New length equal to capacity
+        vec.clear();
+        vec.set_len(vec.capacity()).unwrap();
+        assert_eq!(vec.len(), 3);
+        assert!(!vec.is_empty());
+        assert!(vec.is_full());
+        assert_eq!(vec.as_slice(), [0, 0, 0]);
+
+        // New length zero
+        assert!(vec.set_len(0).is_ok());
+        assert_eq!(vec.len(), 0);
+        assert!(vec.is_empty());
+        assert!(!vec.is_full());
+        assert_eq!(vec.as_slice(), []);
+    }
+
+    #[test]
+    fn checked_set_len() {
+        let mut vec = Vec::<Struct, 3>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        // SAFETY: Shrinking does not expose uninitialized elements.
+        unsafe { vec.checked_set_len(1) }.unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1]));
+        assert_eq!(DROPS.get(), 0);
+
+        // SAFETY: The elements at indexes 1 and 2 were left initialized when shrinking.
+        unsafe { vec.checked_set_len(3) }.unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(DEFAULTS.get(), 0);
+
+        // SAFETY: The capacity is exceeded, so the length is not changed.
+        assert!(matches!(unsafe { vec.checked_set_len(4) }, Err(CapacityError)));
+        assert_eq!(vec.len(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 3);
+    }
+
+    #[test]
+    fn set_len_should_create_default_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+
+        // Length zero, no defaults
+        vec.set_len(0).unwrap();
+        assert_eq!(DEFAULTS.get(), 0);
+
+        // Length error, no defaults
+        vec.set_len(99).unwrap_err();
+        assert_eq!(DEFAULTS.get(), 0);
+
+        // Maximum length, create `CAPACITY` default values
+        vec.set_len(10).unwrap();
+        assert_eq!(DEFAULTS.get(), 10);
+
+        // Smaller length than current, no defaults
+        DEFAULTS.set(0);
+        vec.set_len(5).unwrap();
+        assert_eq!(DEFAULTS.get(), 0);
+
+        // Larger length than current, create `current length - new length` default values
+        DEFAULTS.set(0);
+        vec.set_len(8).unwrap();
+        assert_eq!(DEFAULTS.get(), 3);
+    }
+
+    #[test]
+    fn set_len_should_drop_all_allocated_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        assert_eq!(DROPS.get(), 0);
+
+        let s = Struct { i: 0 };
+        for _ in 1..=5 {
+            vec.push(s.clone()).unwrap();
+        }
+        assert_eq!(DROPS.get(), 0);
+
+        // Same length, no drops
+        vec.set_len(5).unwrap();
+        assert_eq!(DROPS.get(), 0);
+
+        // Length error, no drop
+        vec.set_len(999).unwrap_err();
+        assert_eq!(DROPS.get(), 0);
+
+        // Length smaller, drop elements after
+        vec.set_len(2).unwrap();
+        assert_eq!(DROPS.get(), 3);
+
+        // Same length again, no change in number of drops
+        vec.set_len(2).unwrap();
+        assert_eq!(DROPS.get(), 3);
+
+        // Length zero, drop all
+        DROPS.set(0);
+        vec.set_len(0).unwrap();
+        assert_eq!(DROPS.get(), 2);
+
+        assert_eq!(CLONES.get(), 5); // the five clones before push
+        assert_eq!(DEFAULTS.get(), 0);
+    }
+
+    #[test]
+    fn first() {
+        let mut vec = Vec::<i32, 4>::new();
+        assert!(vec.first().is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.first().unwrap(), &1);
+
+        vec.push(2).unwrap();
+        vec.push(3).unwrap();
+        assert_eq!(vec.first(), Some(&1));
+    }
+
+    #[test]
+    fn first_mut() {
+        let mut vec = Vec::<i32, 4>::new();
+        assert!(vec.first_mut().is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.first_mut().unwrap(), &1);
+
+        vec.push(2).unwrap();
+        vec.push(3).unwrap();
+        assert_eq!(vec.first_mut().unwrap(), &1);
+
+        *vec.first_mut().unwrap() = 4;
+        assert_eq!(vec.first_mut(), Some(&mut 4));
+        assert_eq!(vec.as_slice(), [4, 2, 3]);
+    }
+
+    #[test]
+    fn last() {
+        let mut vec = Vec::<i32, 2>::new();
+        assert!(vec.last().is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.last().unwrap(), &1);
+
+        vec.push(2).unwrap();
+        assert_eq!(vec.last().unwrap(), &2);
+
+        vec.push(3).unwrap_err();
+        assert_eq!(vec.last(), Some(&2));
+    }
+
+    #[test]
+    fn last_mut() {
+        let mut vec = Vec::<i32, 2>::new();
+        assert!(vec.last_mut().is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.last_mut().unwrap(), &1);
+
+        vec.push(2).unwrap();
+        assert_eq!(vec.last_mut().unwrap(), &2);
+
+        vec.push(3).unwrap_err();
+        assert_eq!(vec.last_mut().unwrap(), &2);
+
+        *vec.last_mut().unwrap() = 4;
+        assert_eq!(vec.as_slice(), [1, 4]);
+
+        vec.set_len(1).unwrap();
+        assert_eq!(vec.last_mut(), Some(&mut 1));
+        assert_eq!(vec.as_slice(), [1]);
+    }
+
+    #[test]
+    fn peek_and_peek_back() {
+        let mut vec = Vec::<i32, 4>::new();
+        assert!(vec.peek().is_none());
+        assert!(vec.peek_back().is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.peek(), Some(&1));
+        assert_eq!(vec.peek_back(), Some(&1));
+
+        vec.push(2).unwrap();
+        vec.push(3).unwrap();
+        assert_eq!(vec.peek(), vec.first());
+        assert_eq!(vec.peek_back(), vec.last());
+        assert_eq!(vec.peek(), Some(&1));
+        assert_eq!(vec.peek_back(), Some(&3));
+    }
+
+    #[test]
+    fn get() {
+        let mut vec = Vec::<i32, 2>::new();
+        assert!(vec.get(0).is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.get(0), Some(&1));
+
+        vec.push(2).unwrap();
+        assert_eq!(vec.get(1), Some(&2));
+
+        assert_eq!(vec.get(2), None);
+        assert_eq!(vec.get(3), None);
+    }
+
+    #[test]
+    fn get_mut() {
+        let mut vec = Vec::<i32, 2>::new();
+        assert!(vec.get_mut(0).is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.get_mut(0), Some(&mut 1));
+
+        vec.push(2).unwrap();
+        *vec.get_mut(1).unwrap() = 3;
+        assert_eq!(vec.get_mut(1), Some(&mut 3));
+
+        assert_eq!(vec.get_mut(2), None);
+        assert_eq!(vec.get_mut(3), None);
+    }
+
+    #[test]
+    fn get_wrapping() {
+        let mut vec = Vec::<i32, 3>::new();
+        assert!(vec.get_wrapping(0).is_none());
+        assert!(vec.get_wrapping(usize::MAX).is_none());
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.get_wrapping(0), Some(&1));
+        assert_eq!(vec.get_wrapping(5), Some(&1));
+
+        vec.extend_from_slice(&[2, 3]).unwrap();
+        assert_eq!(vec.get_wrapping(2), Some(&3));
+        assert_eq!(vec.get_wrapping(3), Some(&1));
+        assert_eq!(vec.get_wrapping(4), Some(&2));
+        assert_eq!(vec.get_wrapping(usize::MAX), vec.get(usize::MAX % 3));
+    }
+
+    #[test]
+    fn get_wrapping_mut() {
+        let mut vec = Vec::<i32, 3>::new();
+        assert!(vec.get_wrapping_mut(0).is_none());
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        *vec.get_wrapping_mut(3).unwrap() = 10;
+        *vec.get_wrapping_mut(8).unwrap() = 30;
+        assert_eq!(vec.get_wrapping_mut(4), Some(&mut 2));
+        assert_eq!(vec.as_slice(), [10, 2, 30]);
+    }
+
+    #[test]
+    fn try_get() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.try_get(0), Err(0));
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.try_get(0), Ok(&1));
+        assert_eq!(vec.try_get(2), Ok(&3));
+        assert_eq!(vec.try_get(3), Err(3));
+        assert_eq!(vec.try_get(usize::MAX), Err(3));
+    }
+
+    #[test]
+    fn try_get_mut() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.try_get_mut(0), Err(0));
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        *vec.try_get_mut(0).unwrap() = 10;
+        *vec.try_get_mut(2).unwrap() = 30;
+        assert_eq!(vec.try_get_mut(3), Err(3));
+        assert_eq!(vec.as_slice(), [10, 2, 30]);
+    }
+
+    #[test]
+    fn pop() {
+        let mut vec = Vec::<Struct, 4>::new();
+        assert!(vec.pop().is_none());
+
+        let s1 = Struct { i: 1 };
+        vec.push(s1).unwrap();
+
+        let s2 = Struct { i: 2 };
+        vec.push(s2).unwrap();
+
+        let s3 = Struct { i: 3 };
+        vec.push(s3).unwrap();
+
+        assert_eq!(vec.pop().unwrap().i, 3);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(DROPS.get(), 1);
+
+        assert_eq!(vec.pop().unwrap().i, 2);
+        assert_eq!(vec.pop().unwrap().i, 1);
+        assert!(vec.is_empty());
+        assert!(vec.pop().is_none());
+        assert_eq!(DROPS.get(), 3);
+
+        assert_eq!(DEFAULTS.get(), 0);
+        assert_eq!(CLONES.get(), 0); // from the three pushes
+    }
+
+    fn not<F>(f: F) -> impl Fn(&Struct) -> bool
+    where
+        F: Fn(&Struct) -> bool,
+    {
+        move |s| !f(s)
+    }
+
+    #[test]
+    fn pop_if() {
+        let is_even = |s: &Struct| s.i % 2 == 0;
+
+        let mut vec = Vec::<Struct, 4>::new();
+        assert!(vec.pop_if(is_even).is_none());
+
+        let s1 = Struct { i: 1 };
+        vec.push(s1).unwrap();
+
+        let s2 = Struct { i: 2 };
+        vec.push(s2).unwrap();
+
+        let s3 = Struct { i: 3 };
+        vec.push(s3).unwrap();
+
+        assert!(vec.pop_if(is_even).is_none());
+        assert_eq!(vec.len(), 3);
+        assert_eq!(DROPS.get(), 0);
+
+        assert_eq!(vec.pop_if(not(is_even)).unwrap().i, 3);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(DROPS.get(), 1);
+
+        assert!(vec.pop_if(not(is_even)).is_none());
+        assert_eq!(vec.len(), 2);
+        assert_eq!(DROPS.get(), 1);
+
+        assert_eq!(vec.pop_if(is_even).unwrap().i, 2);
+        assert_eq!(vec.len(), 1);
+        assert_eq!(DROPS.get(), 2);
+
+        assert_eq!(vec.pop_if(not(is_even)).unwrap().i, 1);
+        assert!(vec.is_empty());
+        assert_eq!(DROPS.get(), 3);
+
+        assert!(vec.pop_if(is_even).is_none());
+        assert!(vec.is_empty());
+        assert_eq!(DROPS.get(), 3);
+
+        assert_eq!(DEFAULTS.get(), 0);
+        assert_eq!(CLONES.get(), 0); // from the three pushes
+    }
+
+    #[test]
+    fn iter() {
+        let mut vec = Vec::<i32, 10>::new();
+        for i in 1..=7 {
+            vec.push(i).unwrap();
+        }
+
+        let even_sum = vec.iter().filter(|v| *v % 2 == 0).sum::<i32>();
+        assert_eq!(even_sum, 12);
+
+        assert_eq!(vec.iter().count(), 7);
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut vec = Vec::<i32, 10>::new();
+        for i in 1..=7 {
+            vec.push(i).unwrap();
+        }
+
+        let mut s = 0;
+        for i in &vec {
+            s += i;
+        }
+        assert_eq!(s, 28);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut vec = Vec::<i32, 10>::new();
+        for i in 1..=7 {
+            vec.push(i).unwrap();
+        }
+
+        let even_sum = vec.iter_mut().filter(|v| **v % 2 == 0).map(|v| *v).sum::<i32>();
+        assert_eq!(even_sum, 12);
+
+        assert_eq!(vec.iter().count(), 7);
+    }
+
+    #[test]
+    fn into_iter_mut() {
+        let mut vec = Vec::<i32, 10>::new();
+        for i in 1..=7 {
+            vec.push(i).unwrap();
+        }
+
+        let mut s = 0;
+        for i in &mut vec {
+            *i *= 2;
+            s += *i;
+        }
+        assert_eq!(s, 56);
+    }
+
+    #[test]
+    fn iter_next_back() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.iter().next_back().is_none());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        assert!(vec.iter().rev().eq(&[5, 4, 3, 2, 1]));
+
+        let mut iter = vec.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_rfind() {
+        let mut vec = Vec::<(i32, char), 10>::new();
+        assert!(vec.iter().rfind(|e| e.0 == 1).is_none());
+
+        vec.extend_from_slice(&[(1, 'a'), (2, 'b'), (1, 'c'), (3, 'd'), (1, 'e'), (4, 'f')])
+            .unwrap();
+
+        let mut manual = None;
+        let mut index = vec.len();
+        while index > 0 {
+            index -= 1;
+            if vec.as_slice()[index].0 == 1 {
+                manual = vec.get(index);
+                break;
+            }
+        }
+
+        assert_eq!(vec.iter().rfind(|e| e.0 == 1), manual);
+        assert_eq!(vec.iter().rfind(|e| e.0 == 1), Some(&(1, 'e')));
+        assert_eq!(vec.iter().find(|e| e.0 == 1), Some(&(1, 'a')));
+        assert_eq!(vec.iter().rfind(|e| e.0 == 9), None);
+
+        let mut visited = 0;
+        assert_eq!(
+            vec.iter().rfind(|e| {
+                visited += 1;
+                e.0 == 3
+            }),
+            Some(&(3, 'd'))
+        );
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn iter_mut_next_back() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.iter_mut().next_back().is_none());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        assert!(vec.iter_mut().rev().map(|n| *n).eq([5, 4, 3, 2, 1]));
+
+        let mut iter = vec.iter_mut();
+        *iter.next_back().unwrap() *= 10;
+        *iter.next().unwrap() *= 10;
+        *iter.next_back().unwrap() *= 10;
+        assert!(iter.eq([&mut 2, &mut 3]));
+        assert_eq!(vec.as_slice(), [10, 2, 3, 40, 50]);
+
+        if let Some(last) = vec.iter_mut().rfind(|n| **n < 10) {
+            *last = 0;
+        }
+        assert_eq!(vec.as_slice(), [10, 2, 0, 40, 50]);
+    }
+
+    #[test]
+    fn iter_mut_should_yield_disjoint_references_from_both_ends() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+
+        // Keep all references alive at the same time and write through each of them, so any
+        // aliasing between the references yielded by `next` and `next_back` is detected by Miri.
+        let mut iter = vec.iter_mut();
+        let mut references = Vec::<&mut i32, 10>::new();
+        loop {
+            let reference = if references.len() % 2 == 0 { iter.next() } else { iter.next_back() };
+            match reference {
+                Some(value) => references.push(value).unwrap(),
+                None => break,
+            }
+        }
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+
+        assert!(references.iter().map(|value| **value).eq([1, 7, 2, 6, 3, 5, 4]));
+        for value in &mut references {
+            **value *= 10;
+        }
+        for value in references.iter_mut().rev() {
+            **value += 1;
+        }
+        drop(references);
+
+        assert_eq!(vec.as_slice(), [11, 21, 31, 41, 51, 61, 71]);
+    }
+
+    #[test]
+    fn into_iter_owned() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next().map(|s| s.i), Some(1));
+        assert_eq!(DROPS.get(), 1);
+        assert!(iter.map(|s| s.i).eq([2, 3]));
+        assert_eq!(DROPS.get(), 3);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn into_iter_owned_rev() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+
+        assert!(vec.into_iter().rev().eq([4, 3, 2, 1]));
+    }
+
+    #[test]
+    fn into_iter_owned_next_and_next_back_should_yield_each_element_once() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next_back().map(|s| s.i), Some(5));
+        assert_eq!(iter.next().map(|s| s.i), Some(1));
+        assert_eq!(iter.next_back().map(|s| s.i), Some(4));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        assert_eq!(DROPS.get(), 3);
+
+        assert_eq!(iter.next().map(|s| s.i), Some(2));
+        assert_eq!(iter.next_back().map(|s| s.i), Some(3));
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+        assert_eq!(DROPS.get(), 5);
+
+        drop(iter);
+        assert_eq!(DROPS.get(), 5);
+    }
+
+    #[test]
+    fn into_iter_owned_should_drop_remaining_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let mut iter = vec.into_iter();
+        assert_eq!(iter.next().map(|s| s.i), Some(1));
+        assert_eq!(iter.next_back().map(|s| s.i), Some(5));
+        assert_eq!(DROPS.get(), 2);
+
+        drop(iter);
+        assert_eq!(DROPS.get(), 5);
+    }
+
+    #[test]
+    fn as_slice() {
+        let mut vec = Vec::<i32, 1000>::new();
+        assert_eq!(vec.as_slice(), []);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn as_mut_slice() {
+        let mut vec = Vec::<i32, 1000>::new();
+        assert_eq!(vec.as_mut_slice(), []);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.as_mut_slice(), [1, 2, 3]);
+
+        vec.set_len(1000).unwrap();
+        vec.as_mut_slice().fill(2);
+        assert_eq!(vec.as_slice().iter().sum::<i32>(), 2000);
+    }
+
+    #[test]
+    fn as_uninit_slice() {
+        let mut vec = Vec::<i32, 4>::new();
+        assert_eq!(vec.as_uninit_slice().len(), 4);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        let storage = vec.as_uninit_slice();
+        assert_eq!(storage.len(), 4);
+        assert_eq!(storage.as_ptr().cast::<i32>(), vec.as_slice().as_ptr());
+
+        // SAFETY: The first `len()` slots are initialized.
+        let first = unsafe { storage[0].assume_init_ref() };
+        assert_eq!(*first, 1);
+
+        // SAFETY: The first `len()` slots are initialized.
+        let last = unsafe { storage[2].assume_init_ref() };
+        assert_eq!(*last, 3);
+    }
+
+    #[test]
+    fn extend_from_slice_with_empty_vector_and_empty_slice() {
+        let src = [];
+        let mut dst = Vec::<i32, 3>::new();
+        let result = dst.extend_from_slice(&src);
+
+        assert!(result.is_ok());
+        assert!(dst.is_empty());
+    }
+
+    #[test]
+    fn extend_from_slice_with_empty_vector_and_non_empty_slice_within_capacity() {
+        let src = [1, 2];
+        let mut dst = Vec::<i32, 3>::new();
+        let result = dst.extend_from_slice(&src);
+
+        assert!(result.is_ok());
+        assert_eq!(dst.len(), 2);
+        assert_eq!(dst.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    fn extend_from_slice_with_non_empty_vector_and_empty_slice() {
+        let src = [];
+        let mut dst = Vec::<i32, 3>::new();
+        dst.push(1).unwrap();
+        dst.push(2).unwrap();
+        let result = dst.extend_from_slice(&src);
+
+        assert!(result.is_ok());
+        assert_eq!(dst.len(), 2);
+        assert_eq!(dst.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    fn extend_from_slice_with_non_empty_vector_and_slice_fits_exactly_into_capacity() {
+        let src = [3, 4, 5];
+        let mut dst = Vec::<i32, 5>::new();
+        dst.push(1).unwrap();
+        dst.push(2).unwrap();
+        let result = dst.extend_from_slice(&src);
+
+        assert!(result.is_ok());
+        assert_eq!(dst.len(), 5);
+        assert!(dst.is_full());
+        assert_eq!(dst.as_slice(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn extend_from_slice_with_non_empty_vector_and_slice_exceeds_capacity() {
+        let src = [3, 4, 5, 6];
+        let mut dst = Vec::<i32, 5>::new();
+        dst.push(1).unwrap();
+        dst.push(2).unwrap();
+        let result = dst.extend_from_slice(&src);
+
+        assert!(result.is_err());
+        assert_eq!(dst.len(), 2);
+        assert_eq!(dst.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    fn extend_from_slice_with_vector_full_and_non_empty_slice() {
+        let src = [3, 4, 5, 6];
+        let mut dst = Vec::<i32, 2>::new();
+        dst.push(1).unwrap();
+        dst.push(2).unwrap();
+        let result = dst.extend_from_slice(&src);
+
+        assert!(result.is_err());
+        assert_eq!(dst.len(), 2);
+        assert!(dst.is_full());
+        assert_eq!(dst.as_slice(), [1, 2]);
+    }
+
+    #[test]
+    fn extend_from_slice_with_non_empty_vector_and_non_empty_slice() {
+        let src = [3];
+        let mut dst = Vec::<i32, 5>::new();
+        dst.push(1).unwrap();
+        dst.push(2).unwrap();
+        let result = dst.extend_from_slice(&src);
+
+        assert!(result.is_ok());
+        assert_eq!(dst.len(), 3);
+        assert!(!dst.is_full());
+        assert_eq!(dst.as_slice(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn extend_from_slice_truncating() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert_eq!(vec.extend_from_slice_truncating(&[]), 0);
+        assert!(vec.is_empty());
+
+        assert_eq!(vec.extend_from_slice_truncating(&[1, 2]), 2);
+        assert_eq!(vec.as_slice(), [1, 2]);
+
+        assert_eq!(vec.extend_from_slice_truncating(&[3, 4, 5, 6, 7]), 3);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        assert_eq!(vec.extend_from_slice_truncating(&[8]), 0);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn extend_from_slice_truncating_should_clone_only_appended_elements() {
+        let mut vec = Vec::<Struct, 3>::new();
+        vec.push(Struct { i: 1 }).unwrap();
+
+        let slice = [Struct { i: 2 }, Struct { i: 3 }, Struct { i: 4 }];
+        assert_eq!(vec.extend_from_slice_truncating(&slice), 2);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(CLONES.get(), 2);
+    }
+
+    #[test]
+    fn from_slice_copy() {
+        let empty = Vec::<u8, 3>::from_slice_copy(&[]).unwrap();
+        assert!(empty.is_empty());
+
+        let partial = Vec::<u8, 3>::from_slice_copy(&[1, 2]).unwrap();
+        assert_eq!(partial.as_slice(), [1, 2]);
+        assert_eq!(partial.capacity(), 3);
+
+        let full = Vec::<u8, 3>::from_slice_copy(&[1, 2, 3]).unwrap();
+        assert!(full.is_full());
+        assert_eq!(full.as_slice(), [1, 2, 3]);
+
+        assert!(matches!(Vec::<u8, 3>::from_slice_copy(&[1, 2, 3, 4]), Err(CapacityError)));
+
+        let zst = Vec::<(), 3>::from_slice_copy(&[(), ()]).unwrap();
+        assert_eq!(zst.len(), 2);
+    }
+
+    #[test]
+    fn append_with_enough_room() {
+        let mut vec = Vec::<i32, 5>::new();
+        vec.push(1).unwrap();
+        vec.push(2).unwrap();
+
+        let mut other = Vec::<i32, 20>::new();
+        other.push(3).unwrap();
+        other.push(4).unwrap();
+
+        let result = vec.append(&mut other);
+
+        assert!(result.is_ok());
+        assert_eq!(vec.len(), 4);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+        assert!(other.is_empty());
+        assert_eq!(other.as_slice(), []);
+    }
+
+    #[test]
+    fn append_with_not_enough_room() {
+        let mut vec = Vec::<i32, 2>::new();
+        vec.push(1).unwrap();
+        vec.push(2).unwrap();
+
+        let mut other = Vec::<i32, 20>::new();
+        other.push(3).unwrap();
+        other.push(4).unwrap();
+
+        let result = vec.append(&mut other);
+
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.as_slice(), [1, 2]);
+        assert_eq!(other.len(), 2);
+        assert_eq!(other.as_slice(), [3, 4]);
+    }
+
+    #[test]
+    fn append_move() {
+        let mut vec = Vec::<Struct, 5>::new();
+        let mut empty = Vec::<Struct, 3>::new();
+        vec.append_move(&mut empty).unwrap();
+        assert!(vec.is_empty());
+
+        vec.push(Struct { i: 1 }).unwrap();
+        let mut other = Vec::<Struct, 10>::new();
+        for i in 2..=4 {
+            other.push(Struct { i }).unwrap();
+        }
+
+        vec.append_move(&mut other).unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3, 4]));
+        assert!(other.is_empty());
+        assert_eq!(CLONES.get(), 0);
+        assert_eq!(DROPS.get(), 0);
+
+        drop(other);
+        drop(vec);
+        assert_eq!(DROPS.get(), 4);
+    }
+
+    #[test]
+    fn append_move_with_not_enough_room() {
+        let mut vec = Vec::<Struct, 3>::new();
+        vec.push(Struct { i: 1 }).unwrap();
+        let mut other = Vec::<Struct, 10>::new();
+        for i in 2..=4 {
+            other.push(Struct { i }).unwrap();
+        }
+
+        assert!(matches!(vec.append_move(&mut other), Err(CapacityError)));
+        assert!(vec.iter().map(|s| s.i).eq([1]));
+        assert!(other.iter().map(|s| s.i).eq([2, 3, 4]));
+        assert_eq!(other.len(), 3);
+        assert_eq!(DROPS.get(), 0);
+        assert_eq!(CLONES.get(), 0);
+
+        drop(other.pop());
+        vec.append_move(&mut other).unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn append_move_should_move_all_elements_when_they_fit_exactly() {
+        let mut vec = Vec::<i32, 4>::new();
+        vec.push(1).unwrap();
+        let mut other = vec![2, 3, 4];
+
+        vec.append_move(&mut other).unwrap();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+        assert!(other.is_empty());
+
+        other.push(5).unwrap();
+        assert!(matches!(vec.append_move(&mut other), Err(CapacityError)));
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+        assert_eq!(other.as_slice(), [5]);
+    }
+
+    #[test]
+    fn try_append_exact() {
+        let mut vec = Vec::<Struct, 4>::new();
+        vec.push(Struct { i: 1 }).unwrap();
+        let mut other = Vec::<Struct, 10>::new();
+        for i in 2..=5 {
+            other.push(Struct { i }).unwrap();
+        }
+
+        assert!(matches!(vec.try_append_exact(&mut other), Err(CapacityError)));
+        assert!(vec.iter().map(|s| s.i).eq([1]));
+        assert!(other.iter().map(|s| s.i).eq([2, 3, 4, 5]));
+        assert_eq!(DROPS.get(), 0);
+        assert_eq!(CLONES.get(), 0);
+
+        drop(other.pop());
+        vec.try_append_exact(&mut other).unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3, 4]));
+        assert!(other.is_empty());
+        assert_eq!(DROPS.get(), 1);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn clone() {
+        let mut vec = Vec::<i32, 5>::new();
+        let elements = [1, 2, 3];
+        vec.extend_from_slice(&elements).unwrap();
+
+        let new = vec.clone();
+        assert_eq!(new.len(), 3);
+        assert_eq!(new.as_slice(), elements);
+    }
+
+    #[test]
+    fn try_from_array() {
+        let empty = Vec::<i32, 3>::try_from([]).unwrap();
+        assert!(empty.is_empty());
+
+        let partial = Vec::<i32, 5>::try_from([1, 2, 3]).unwrap();
+        assert_eq!(partial.as_slice(), [1, 2, 3]);
+        assert_eq!(partial.capacity(), 5);
+
+        let full: Vec<i32, 3> = [1, 2, 3].try_into().unwrap();
+        assert!(full.is_full());
+
+        assert!(matches!(Vec::<i32, 2>::try_from([1, 2, 3]), Err(CapacityError)));
+    }
+
+    #[test]
+    fn try_from_array_should_move_elements() {
+        let vec = Vec::<Struct, 5>::try_from([Struct { i: 1 }, Struct { i: 2 }]).unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2]));
+        assert_eq!(DROPS.get(), 0);
+        assert_eq!(CLONES.get(), 0);
+
+        assert!(Vec::<Struct, 1>::try_from([Struct { i: 3 }, Struct { i: 4 }]).is_err());
+        assert_eq!(DROPS.get(), 2);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 4);
+    }
+
+    #[test]
+    fn eq() {
+        let mut a = Vec::<i32, 5>::new();
+        a.extend_from_slice(&[1, 2, 3]).unwrap();
+
+        let mut b = Vec::<i32, 10>::new();
+        assert_ne!(a, b);
+
+        b.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(a, b);
+
+        b.clear();
+        b.extend_from_slice(&[9, 9, 9]).unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn send_and_sync() {
+        assert_is_send_and_sync::<Vec<i32, 4>>();
+        assert_is_send_and_sync::<Vec<Struct, 4>>();
+        assert_is_send_and_sync::<Vec<&str, 4>>();
+    }
+
+    #[test]
+    fn const_eq() {
+        static EMPTY: Vec<u32, 3> = Vec::new();
+        static OTHER_EMPTY: Vec<u32, 3> = Vec::new();
+        const { assert!(EMPTY.const_eq(&OTHER_EMPTY), "empty vectors should be equal") };
+
+        let mut a = Vec::<i32, 3>::new();
+        let mut b = Vec::<i32, 3>::new();
+        assert!(a.const_eq(&b));
+
+        a.extend_from_slice(&[1, -2, 3]).unwrap();
+        assert!(!a.const_eq(&b));
+
+        b.extend_from_slice(&[1, -2]).unwrap();
+        assert!(!a.const_eq(&b));
+
+        b.push(4).unwrap();
+        assert!(!a.const_eq(&b));
+
+        assert_eq!(b.pop(), Some(4));
+        b.push(3).unwrap();
+        assert!(a.const_eq(&b));
+
+        let chars = Vec::<char, 2>::from_iter_truncating(['a', 'b']);
+        assert!(chars.const_eq(&Vec::from_iter_truncating(['a', 'b'])));
+        assert!(!chars.const_eq(&Vec::from_iter_truncating(['a', 'c'])));
+    }
+
+    #[test]
+    fn hash() {
+        use core::hash::BuildHasher as _;
+        use std::hash::RandomState;
+
+        let state = RandomState::new();
+
+        let mut a = Vec::<i32, 5>::new();
+        a.extend_from_slice(&[1, 2, 3]).unwrap();
+
+        let mut b = Vec::<i32, 5>::new();
+        b.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(state.hash_one(&a), state.hash_one(&b));
+        assert_eq!(state.hash_one(&a), state.hash_one([1, 2, 3].as_slice()));
+
+        b.push(4).unwrap();
+        assert_ne!(state.hash_one(&a), state.hash_one(&b));
+    }
+
+    #[test]
+    fn debug() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert_eq!(format!("{vec:?}"), "[]");
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(format!("{vec:?}"), "[1, 2, 3]");
+        assert_eq!(format!("{vec:?}"), format!("{:?}", vec.as_slice()));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn eq_alloc_vec() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert_eq!(vec, alloc::vec![]);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec, alloc::vec![1, 2, 3]);
+        assert_eq!(alloc::vec![1, 2, 3], vec);
+        assert_ne!(vec, alloc::vec![1, 2]);
+        assert_ne!(alloc::vec![1, 2, 4], vec);
+    }
+
+    #[test]
+    fn cmp() {
+        let mut a = Vec::<i32, 5>::new();
+        a.extend_from_slice(&[1, 2, 3]).unwrap();
+
+        let mut b = Vec::<i32, 10>::new();
+        assert!(a >= b);
+
+        b.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert!(a >= b);
+        assert!(a <= b);
+
+        b.clear();
+        b.extend_from_slice(&[9, 9]).unwrap();
+        assert!(a < b);
+
+        b.clear();
+        b.extend_from_slice(&[1, 2]).unwrap();
+        assert!(a > b);
+    }
+
+    #[test]
+    fn going_out_of_scope_should_drop_all_allocated_elements() {
+        let s = Struct { i: 0 };
+
+        {
+            let mut vec = Vec::<Struct, 10>::new();
+            assert_eq!(DROPS.get(), 0);
+
+            for _ in 1..=3 {
+                vec.push(s.clone()).unwrap();
+            }
+            assert_eq!(DROPS.get(), 0);
+        };
+
+        assert_eq!(DROPS.get(), 3);
+        assert_eq!(CLONES.get(), 3); // the three clones before push
+    }
+
+    #[test]
+    fn retain_swap() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.retain_swap(|_| false);
+        assert!(vec.is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+        vec.retain_swap(|_| true);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6, 7]);
+
+        vec.retain_swap(|n| *n % 2 == 0);
+        assert_eq!(vec.as_slice(), [6, 2, 4]);
+
+        vec.retain_swap(|n| *n > 3);
+        assert_eq!(vec.as_slice(), [6, 4]);
+
+        vec.retain_swap(|_| false);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn retain_swap_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.retain_swap(|s| s.i != 2 && s.i != 4);
+        assert!(vec.iter().map(|s| s.i).eq([1, 5, 3]));
+        assert_eq!(DROPS.get(), 2);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn retain_swap_should_keep_vector_valid_when_predicate_panics() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            vec.retain_swap(|s| {
+                assert!(s.i != 5, "predicate panic");
+                s.i != 2
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(DROPS.get(), 1);
+        assert!(vec.iter().map(|s| s.i).eq([1, 6, 3, 4, 5]));
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+    }
+
+    #[test]
+    fn into_full_array() {
+        let empty = Vec::<i32, 3>::new();
+        assert!(matches!(empty.into_full_array::<3>(), Err(CapacityError)));
+
+        let mut partial = Vec::<i32, 3>::new();
+        partial.extend_from_slice(&[1, 2]).unwrap();
+        assert!(matches!(partial.into_full_array::<2>(), Err(CapacityError)));
+
+        let mut full = Vec::<i32, 3>::new();
+        full.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert!(matches!(full.clone().into_full_array::<2>(), Err(CapacityError)));
+        assert!(matches!(full.clone().into_full_array::<4>(), Err(CapacityError)));
+        assert_eq!(full.into_full_array::<3>().unwrap(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn into_full_array_should_move_elements() {
+        let mut vec = Vec::<Struct, 3>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let array = vec.into_full_array::<3>().unwrap();
+        assert_eq!(DROPS.get(), 0);
+        assert_eq!(CLONES.get(), 0);
+
+        drop(array);
+        assert_eq!(DROPS.get(), 3);
+    }
+
+    #[test]
+    fn into_full_array_should_drop_elements_on_error() {
+        let mut vec = Vec::<Struct, 3>::new();
+        for i in 1..=2 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        assert!(vec.into_full_array::<3>().is_err());
+        assert_eq!(DROPS.get(), 2);
+    }
+
+    #[test]
+    fn into_chunks() {
+        let empty = Vec::<i32, 5>::new();
+        let no_chunks: Vec<Vec<i32, 2>, 1> = empty.into_chunks().unwrap();
+        assert!(no_chunks.is_empty());
+
+        let even = vec![1, 2, 3, 4];
+        let pairs: Vec<Vec<i32, 2>, 2> = even.into_chunks().unwrap();
+        assert!(pairs.iter().map(Vec::as_slice).eq([[1, 2], [3, 4]]));
+
+        let odd = vec![1, 2, 3, 4, 5];
+        let rejected = odd.into_chunks::<2, 2>().unwrap_err();
+        assert_eq!(rejected.as_slice(), [1, 2, 3, 4, 5]);
+
+        let chunks: Vec<Vec<i32, 2>, 3> = rejected.into_chunks().unwrap();
+        assert!(chunks.iter().map(Vec::len).eq([2, 2, 1]));
+        assert!(chunks.iter().flatten().eq(&[1, 2, 3, 4, 5]));
+
+        let whole = vec![1, 2, 3];
+        let single: Vec<Vec<i32, 4>, 1> = whole.into_chunks().unwrap();
+        assert!(single.iter().map(Vec::as_slice).eq([[1, 2, 3]]));
+    }
+
+    #[test]
+    fn into_chunks_should_move_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let rejected = vec.into_chunks::<2, 2>().unwrap_err();
+        assert_eq!(DROPS.get(), 0);
+
+        let chunks: Vec<Vec<Struct, 2>, 3> = rejected.into_chunks().unwrap();
+        assert!(chunks.iter().map(Vec::len).eq([2, 2, 1]));
+        assert!(chunks.iter().flatten().map(|s| s.i).eq([1, 2, 3, 4, 5]));
+        assert_eq!(DROPS.get(), 0);
+
+        drop(chunks);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn into_chunks_with_zero_chunk_size() {
+        let vec = vec![1, 2, 3];
+        let _ = vec.into_chunks::<0, 3>();
+    }
+
+    #[test]
+    fn partition_into() {
+        let empty = Vec::<i32, 5>::new();
+        let (none_left, none_right) = empty.partition_into::<1, 1, _>(|_| true).unwrap();
+        assert!(none_left.is_empty());
+        assert!(none_right.is_empty());
+
+        let vec = vec![1, 2, 3, 4, 5];
+        let (even, odd) = vec.partition_into::<2, 3, _>(|n| n % 2 == 0).unwrap();
+        assert_eq!(even.as_slice(), [2, 4]);
+        assert_eq!(odd.as_slice(), [1, 3, 5]);
+
+        let all = vec![1, 2, 3];
+        let (left, right) = all.partition_into::<3, 1, _>(|_| true).unwrap();
+        assert_eq!(left.as_slice(), [1, 2, 3]);
+        assert!(right.is_empty());
+
+        let overflow_left = vec![1, 2, 3];
+        let unchanged_left = overflow_left.partition_into::<1, 3, _>(|n| *n > 1).unwrap_err();
+        assert_eq!(unchanged_left.as_slice(), [1, 2, 3]);
+
+        let overflow_right = vec![1, 2, 3];
+        let unchanged_right = overflow_right.partition_into::<3, 1, _>(|n| *n > 2).unwrap_err();
+        assert_eq!(unchanged_right.as_slice(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn partition_into_should_move_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let mut calls = 0;
+        let rejected = vec
+            .partition_into::<1, 5, _>(|s| {
+                calls += 1;
+                s.i > 3
+            })
+            .unwrap_err();
+        assert_eq!(calls, 5);
+        assert!(rejected.iter().map(|s| s.i).eq([1, 2, 3, 4, 5]));
+        assert_eq!(DROPS.get(), 0);
+
+        let (high, low) = rejected.partition_into::<2, 3, _>(|s| s.i > 3).unwrap();
+        assert!(high.iter().map(|s| s.i).eq([4, 5]));
+        assert!(low.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(DROPS.get(), 0);
+
+        drop(high);
+        drop(low);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn to_array() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert_eq!(vec.to_array::<0>(), Some([]));
+        assert_eq!(vec.to_array::<1>(), None);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.to_array::<2>(), Some([1, 2]));
+        assert_eq!(vec.to_array::<3>(), Some([1, 2, 3]));
+        assert_eq!(vec.to_array::<4>(), None);
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn to_array_should_clone_elements() {
+        let mut vec = Vec::<Struct, 5>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let array = vec.to_array::<2>().unwrap();
+        assert_eq!(array.map(|s| s.i), [1, 2]);
+        assert_eq!(CLONES.get(), 2);
+        assert_eq!(DROPS.get(), 2);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
+    }
+
+    #[test]
+    fn each_ref() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert!(vec.each_ref::<1>().is_none());
+        assert_eq!(vec.each_ref::<0>(), Some([]));
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.each_ref(), Some([&1, &2]));
+        assert_eq!(vec.each_ref(), Some([&1, &2, &3]));
+        assert!(vec.each_ref::<4>().is_none());
+    }
+
+    #[test]
+    fn each_mut() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert!(vec.each_mut::<1>().is_none());
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        let [first, second] = vec.each_mut().unwrap();
+        mem::swap(first, second);
+        assert_eq!(vec.as_slice(), [2, 1, 3]);
+
+        assert!(vec.each_mut::<4>().is_none());
+    }
+
+    #[test]
+    fn as_cells() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.as_cells().is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        let cells = vec.as_cells();
+        assert_eq!(cells.len(), 3);
+
+        let first = &cells[0];
+        let last = &cells[2];
+        first.set(last.get() * 10);
+        last.set(first.get() + 1);
+
+        assert_eq!(vec.as_slice(), [30, 2, 31]);
+    }
+
+    #[test]
+    fn rchunks_exact() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.rchunks_exact(2).count(), 0);
+        assert!(vec.rchunks_exact(2).remainder().is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+
+        let mut chunks = vec.rchunks_exact(3);
+        assert_eq!(chunks.next(), Some(&[5, 6, 7][..]));
+        assert_eq!(chunks.next(), Some(&[2, 3, 4][..]));
+        assert_eq!(chunks.next(), None);
+        assert_eq!(chunks.remainder(), [1]);
+
+        let mut whole = vec.rchunks_exact(7);
+        assert_eq!(whole.next(), Some(&[1, 2, 3, 4, 5, 6, 7][..]));
+        assert_eq!(whole.next(), None);
+        assert!(whole.remainder().is_empty());
+    }
+
+    #[test]
+    fn rchunks_mut() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.rchunks_mut(4).count(), 0);
+
+        vec.set_len(10).unwrap();
+        let blocks = [*b"wxyz", *b"abcd", *b"0123"];
+        for (chunk, block) in vec.rchunks_mut(4).zip(blocks) {
+            let start = block.len() - chunk.len();
+            chunk.copy_from_slice(&block[start..]);
+        }
+        assert_eq!(vec.as_slice(), b"23abcdwxyz");
+
+        let mut chunks = vec.rchunks_mut(3);
+        assert_eq!(chunks.next().map(|chunk| chunk.len()), Some(3));
+        assert_eq!(chunks.last().as_deref(), Some(&b"2"[..]));
+    }
+
+    #[test]
+    fn for_each_chunk_mut() {
+        let mut vec = Vec::<i32, 10>::new();
+        let mut calls = 0;
+        vec.for_each_chunk_mut(3, |_| calls += 1);
+        assert_eq!(calls, 0);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+        let mut lengths = Vec::<usize, 10>::new();
+        vec.for_each_chunk_mut(3, |chunk| {
+            lengths.push(chunk.len()).unwrap();
+            let sum = chunk.iter().sum();
+            chunk.fill(sum);
+        });
+        assert_eq!(lengths.as_slice(), [3, 3, 1]);
+        assert_eq!(vec.as_slice(), [6, 6, 6, 15, 15, 15, 7]);
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn for_each_chunk_mut_with_zero_size() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.for_each_chunk_mut(0, |_| {});
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn rchunks_exact_with_chunk_size_zero() {
+        let _ = Vec::<i32, 10>::new().rchunks_exact(0);
+    }
+
+    #[test]
+    fn split() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.split(|n| *n == 0).eq([&[][..]]));
+
+        vec.extend_from_slice(&[0, 1, 2, 0, 3, 0]).unwrap();
+        assert!(vec.split(|n| *n == 0).eq([&[][..], &[1, 2], &[3], &[]]));
+        assert!(vec.split(|n| *n == 9).eq([&[0, 1, 2, 0, 3, 0][..]]));
+    }
+
+    #[test]
+    fn splitn() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.splitn(2, |n| *n == 0).eq([&[][..]]));
+
+        vec.extend_from_slice(&[0, 1, 2, 0, 3, 0]).unwrap();
+        assert_eq!(vec.splitn(0, |n| *n == 0).count(), 0);
+        assert!(vec.splitn(1, |n| *n == 0).eq([&[0, 1, 2, 0, 3, 0][..]]));
+        assert!(vec.splitn(3, |n| *n == 0).eq([&[][..], &[1, 2], &[3, 0]]));
+    }
+
+    #[test]
+    fn rsplit() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.rsplit(|n| *n == 0).eq([&[][..]]));
+
+        vec.extend_from_slice(&[0, 1, 2, 0, 3, 0]).unwrap();
+        assert!(vec.rsplit(|n| *n == 0).eq([&[][..], &[3], &[1, 2], &[]]));
+        assert!(vec.rsplit(|n| *n == 9).eq([&[0, 1, 2, 0, 3, 0][..]]));
+    }
+
+    #[test]
+    fn rsplitn() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.rsplitn(2, |n| *n == 0).eq([&[][..]]));
+
+        vec.extend_from_slice(&[0, 1, 2, 0, 3, 0]).unwrap();
+        assert_eq!(vec.rsplitn(0, |n| *n == 0).count(), 0);
+        assert!(vec.rsplitn(1, |n| *n == 0).eq([&[0, 1, 2, 0, 3, 0][..]]));
+        assert!(vec.rsplitn(3, |n| *n == 0).eq([&[][..], &[3], &[0, 1, 2]]));
+    }
+
+    #[test]
+    fn group_by() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.group_by(|a, b| a == b).count(), 0);
+
+        vec.push(1).unwrap();
+        assert!(vec.group_by(|a, b| a == b).eq([&[1][..]]));
+
+        vec.extend_from_slice(&[2, 3, 5, 6, 8]).unwrap();
+        assert!(vec.group_by(|a, b| a + 1 == *b).eq([&[1, 2, 3][..], &[5, 6], &[8]]));
+        assert!(vec.group_by(|_, _| true).eq([&[1, 2, 3, 5, 6, 8][..]]));
+        assert_eq!(vec.group_by(|_, _| false).count(), 6);
+        assert!(vec.group_by(|a, b| a + 1 == *b).rev().eq([&[8][..], &[5, 6], &[1, 2, 3]]));
+    }
+
+    #[test]
+    fn split_once() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.split_once(|_| true), None);
+        assert_eq!(vec.rsplit_once(|_| true), None);
+
+        vec.extend_from_slice(b"a,b,,c").unwrap();
+        assert_eq!(vec.split_once(|b| *b == b';'), None);
+        assert_eq!(vec.rsplit_once(|b| *b == b';'), None);
+
+        assert_eq!(vec.split_once(|b| *b == b','), Some((&b"a"[..], &b"b,,c"[..])));
+        assert_eq!(vec.rsplit_once(|b| *b == b','), Some((&b"a,b,"[..], &b"c"[..])));
+
+        assert_eq!(vec.split_once(|b| *b == b'a'), Some((&b""[..], &b",b,,c"[..])));
+        assert_eq!(vec.rsplit_once(|b| *b == b'c'), Some((&b"a,b,,"[..], &b""[..])));
+    }
+
+    #[test]
+    fn split_at_value() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.split_at_value(&b'='), None);
+
+        vec.extend_from_slice(b"a=b=c").unwrap();
+        assert_eq!(vec.split_at_value(&b';'), None);
+        assert_eq!(vec.split_at_value(&b'='), Some((&b"a"[..], &b"b=c"[..])));
+        assert_eq!(vec.split_at_value(&b'a'), Some((&b""[..], &b"=b=c"[..])));
+        assert_eq!(vec.split_at_value(&b'c'), Some((&b"a=b="[..], &b""[..])));
+    }
+
+    #[test]
+    fn replace() {
+        let mut vec = Vec::<Struct, 3>::new();
+        assert!(vec.replace(0, Struct { i: 1 }).is_none());
+        assert_eq!(DROPS.get(), 1); // the rejected value
+
+        vec.push(Struct { i: 1 }).unwrap();
+        vec.push(Struct { i: 2 }).unwrap();
+
+        assert_eq!(vec.replace(0, Struct { i: 3 }).unwrap().i, 1);
+        assert_eq!(vec.replace(1, Struct { i: 4 }).unwrap().i, 2);
+        assert_eq!(DROPS.get(), 3);
+
+        assert!(vec.replace(2, Struct { i: 5 }).is_none());
+        assert_eq!(DROPS.get(), 4);
+
+        assert!(vec.iter().map(|s| s.i).eq([3, 4]));
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn insert_if_room() {
+        let mut vec = Vec::<i32, 4>::new();
+        assert_eq!(vec.insert_if_room(1, 1), Err((1, 1)));
+        assert!(vec.is_empty());
+
+        assert!(vec.insert_if_room(0, 2).is_ok());
+        assert!(vec.insert_if_room(0, 1).is_ok());
+        assert!(vec.insert_if_room(2, 4).is_ok());
+        assert!(vec.insert_if_room(2, 3).is_ok());
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+
+        assert_eq!(vec.insert_if_room(0, 5), Err((0, 5)));
+        assert_eq!(vec.insert_if_room(9, 6), Err((9, 6)));
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn insert_if_room_should_give_back_value_without_dropping() {
+        let mut vec = Vec::<Struct, 2>::new();
+
+        let (index, value) = vec.insert_if_room(1, Struct { i: 1 }).unwrap_err();
+        assert_eq!(index, 1);
+        assert_eq!(value.i, 1);
+        assert_eq!(DROPS.get(), 0);
+
+        vec.insert_if_room(0, value).unwrap();
+        vec.insert_if_room(0, Struct { i: 2 }).unwrap();
+
+        let (full_index, full_value) = vec.insert_if_room(0, Struct { i: 3 }).unwrap_err();
+        assert_eq!(full_index, 0);
+        assert_eq!(full_value.i, 3);
+        assert_eq!(DROPS.get(), 0);
+
+        assert!(vec.iter().map(|s| s.i).eq([2, 1]));
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn insert_sorted_by_key() {
+        let mut vec = Vec::<(i32, char), 5>::new();
+        assert_eq!(vec.insert_sorted_by_key((2, 'a'), |pair| pair.0).unwrap(), 0);
+        assert_eq!(vec.insert_sorted_by_key((1, 'b'), |pair| pair.0).unwrap(), 0);
+        assert_eq!(vec.insert_sorted_by_key((3, 'c'), |pair| pair.0).unwrap(), 2);
+        assert_eq!(vec.insert_sorted_by_key((2, 'd'), |pair| pair.0).unwrap(), 2);
+        assert_eq!(vec.insert_sorted_by_key((2, 'e'), |pair| pair.0).unwrap(), 3);
+        assert_eq!(vec.as_slice(), [(1, 'b'), (2, 'a'), (2, 'd'), (2, 'e'), (3, 'c')]);
+
+        assert!(matches!(vec.insert_sorted_by_key((0, 'f'), |pair| pair.0), Err(CapacityError)));
+        assert_eq!(vec.as_slice(), [(1, 'b'), (2, 'a'), (2, 'd'), (2, 'e'), (3, 'c')]);
+    }
+
+    #[test]
+    fn insert_sorted_by_key_should_not_call_key_when_full() {
+        let mut vec = vec![1, 2];
+        let mut calls = 0;
+        let result = vec.insert_sorted_by_key(3, |n| {
+            calls += 1;
+            *n
+        });
+        assert!(matches!(result, Err(CapacityError)));
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn sort_dedup() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.sort_dedup();
+        assert!(vec.is_empty());
+
+        vec.push(1).unwrap();
+        vec.sort_dedup();
+        assert_eq!(vec.as_slice(), [1]);
+
+        vec.clear();
+        vec.extend_from_slice(&[3, 1, 2, 1, 3]).unwrap();
+        vec.sort_dedup();
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
+
+        vec.clear();
+        vec.extend_from_slice(&[5, 5, 5, 5]).unwrap();
+        vec.sort_dedup();
+        assert_eq!(vec.as_slice(), [5]);
+
+        vec.clear();
+        vec.extend_from_slice(&[4, 3, 2, 1]).unwrap();
+        vec.sort_dedup();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn sort_dedup_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in [2, 1, 2, 3, 1, 2] {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.sort_dedup();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(DROPS.get(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn dedup() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.dedup();
+        assert!(vec.is_empty());
+
+        vec.push(1).unwrap();
+        vec.dedup();
+        assert_eq!(vec.as_slice(), [1]);
+
+        vec.extend_from_slice(&[1, 2, 3, 3, 3, 1, 2, 2]).unwrap();
+        vec.dedup();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 1, 2]);
+
+        vec.dedup();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 1, 2]);
+
+        vec.clear();
+        vec.extend_from_slice(&[7; 10]).unwrap();
+        vec.dedup();
+        assert_eq!(vec.as_slice(), [7]);
+    }
+
+    #[test]
+    fn dedup_should_keep_first_element_of_each_run() {
+        #[derive(Debug)]
+        struct Record {
+            key: u8,
+            payload: char,
+        }
+
+        impl PartialEq for Record {
+            fn eq(&self, other: &Self) -> bool {
+                self.key == other.key
+            }
+        }
+
+        let mut vec = Vec::<Record, 10>::new();
+        for (key, payload) in [(1, 'a'), (1, 'b'), (2, 'c'), (3, 'd'), (3, 'e'), (3, 'f'), (1, 'g')]
+        {
+            vec.push(Record { key, payload }).unwrap();
+        }
+
+        vec.dedup();
+        assert!(vec.iter().map(|r| (r.key, r.payload)).eq([
+            (1, 'a'),
+            (2, 'c'),
+            (3, 'd'),
+            (1, 'g')
+        ]));
+    }
+
+    #[test]
+    fn dedup_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in [1, 1, 2, 2, 2, 1] {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.dedup();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 1]));
+        assert_eq!(DROPS.get(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn dedup_should_keep_vector_valid_when_comparison_panics() {
+        struct Faulty(Struct);
+
+        impl PartialEq for Faulty {
+            fn eq(&self, other: &Self) -> bool {
+                assert!(self.0.i != 9, "comparison panic");
+                self.0 == other.0
+            }
+        }
+
+        let mut vec = Vec::<Faulty, 10>::new();
+        for i in [1, 1, 2, 2, 9, 3, 3] {
+            vec.push(Faulty(Struct { i })).unwrap();
+        }
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| vec.dedup()));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 7);
+        assert!(vec.iter().map(|f| f.0.i).eq([1, 2, 1, 2, 9, 3, 3]));
+        assert_eq!(DROPS.get(), 0);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 7);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn retain_unique() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.retain_unique();
+        assert!(vec.is_empty());
+
+        vec.push(1).unwrap();
+        vec.retain_unique();
+        assert_eq!(vec.as_slice(), [1]);
+
+        vec.extend_from_slice(&[2, 1, 3, 2]).unwrap();
+        vec.retain_unique();
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
+
+        vec.extend_from_slice(&[3, 3, 4, 1, 5, 4]).unwrap();
+        vec.retain_unique();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.retain_unique();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn retain_unique_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in [1, 2, 1, 3, 2, 1] {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.retain_unique();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(DROPS.get(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn run_length_encode() {
+        let mut vec = Vec::<i32, 10>::new();
+        let empty: Vec<(i32, usize), 1> = vec.run_length_encode(|n| *n).unwrap();
+        assert!(empty.is_empty());
+
+        vec.extend_from_slice(&[1, 1, 2, 3, 3, 3, 1]).unwrap();
+        let runs: Vec<(i32, usize), 4> = vec.run_length_encode(|n| *n).unwrap();
+        assert_eq!(runs.as_slice(), [(1, 2), (2, 1), (3, 3), (1, 1)]);
+
+        let parity: Vec<(bool, usize), 4> = vec.run_length_encode(|n| n % 2 == 0).unwrap();
+        assert_eq!(parity.as_slice(), [(false, 2), (true, 1), (false, 4)]);
+
+        let single: Vec<((), usize), 1> = vec.run_length_encode(|_| ()).unwrap();
+        assert_eq!(single.as_slice(), [((), 7)]);
+
+        assert!(matches!(vec.run_length_encode::<_, 3, _>(|n| *n), Err(CapacityError)));
+    }
+
+    #[test]
+    fn split_into_halves() {
+        let (empty_front, empty_back) = Vec::<i32, 10>::new().split_into_halves();
+        assert!(empty_front.is_empty());
+        assert!(empty_back.is_empty());
+
+        let mut one = Vec::<i32, 10>::new();
+        one.push(1).unwrap();
+        let (one_front, one_back) = one.split_into_halves();
+        assert_eq!(one_front.as_slice(), [1]);
+        assert!(one_back.is_empty());
+
+        let mut even = Vec::<i32, 4>::new();
+        even.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+        let (even_front, even_back) = even.split_into_halves();
+        assert_eq!(even_front.as_slice(), [1, 2]);
+        assert_eq!(even_back.as_slice(), [3, 4]);
+        assert_eq!(even_back.capacity(), 4);
+
+        let mut odd = Vec::<i32, 10>::new();
+        odd.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+        let (odd_front, odd_back) = odd.split_into_halves();
+        assert_eq!(odd_front.as_slice(), [1, 2, 3, 4]);
+        assert_eq!(odd_back.as_slice(), [5, 6, 7]);
+    }
+
+    #[test]
+    fn split_into_halves_should_move_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let (front, back) = vec.split_into_halves();
+        assert!(front.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert!(back.iter().map(|s| s.i).eq([4, 5]));
+        assert_eq!(DROPS.get(), 0);
+
+        drop(front);
+        assert_eq!(DROPS.get(), 3);
+
+        drop(back);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn interleave() {
+        let mut a = Vec::<i32, 5>::new();
+        let mut b = Vec::<i32, 10>::new();
+        assert!(a.interleave::<10, 1>(&b).unwrap().is_empty());
+
+        a.extend_from_slice(&[1, 3, 5]).unwrap();
+        assert_eq!(a.interleave::<10, 3>(&b).unwrap().as_slice(), [1, 3, 5]);
+        assert_eq!(b.interleave::<5, 3>(&a).unwrap().as_slice(), [1, 3, 5]);
+
+        b.extend_from_slice(&[2, 4, 6]).unwrap();
+        assert_eq!(a.interleave::<10, 6>(&b).unwrap().as_slice(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(b.interleave::<5, 6>(&a).unwrap().as_slice(), [2, 1, 4, 3, 6, 5]);
+
+        b.extend_from_slice(&[8, 10]).unwrap();
+        assert_eq!(a.interleave::<10, 8>(&b).unwrap().as_slice(), [1, 2, 3, 4, 5, 6, 8, 10]);
+        assert_eq!(b.interleave::<5, 20>(&a).unwrap().as_slice(), [2, 1, 4, 3, 6, 5, 8, 10]);
+
+        assert!(matches!(a.interleave::<10, 7>(&b), Err(CapacityError)));
+    }
+
+    #[test]
+    fn interleave_should_clone_elements() {
+        let mut a = Vec::<Struct, 2>::new();
+        a.push(Struct { i: 1 }).unwrap();
+        let mut b = Vec::<Struct, 2>::new();
+        b.push(Struct { i: 2 }).unwrap();
+        b.push(Struct { i: 3 }).unwrap();
+
+        assert!(a.interleave::<2, 2>(&b).is_err());
+        assert_eq!(CLONES.get(), 0);
+
+        let vec = a.interleave::<2, 4>(&b).unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(CLONES.get(), 3);
+        assert_eq!(DROPS.get(), 0);
+    }
+
+    #[test]
+    fn zip_with() {
+        let mut a = Vec::<i32, 5>::new();
+        let mut b = Vec::<i32, 10>::new();
+        assert!(a.zip_with::<_, _, 10, 1, _>(&b, |x, y| x + y).unwrap().is_empty());
+
+        a.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert!(a.zip_with::<_, _, 10, 1, _>(&b, |x, y| x + y).unwrap().is_empty());
+
+        b.extend_from_slice(&[10, 20, 30, 40]).unwrap();
+        let products = a.zip_with::<_, _, 10, 3, _>(&b, |x, y| x * y).unwrap();
+        assert_eq!(products.as_slice(), [10, 40, 90]);
+
+        let pairs = b.zip_with::<_, _, 5, 5, _>(&a, |x, y| (*x, *y)).unwrap();
+        assert_eq!(pairs.as_slice(), [(10, 1), (20, 2), (30, 3)]);
+
+        let mut calls = 0;
+        let result = a.zip_with::<_, i32, 10, 2, _>(&b, |x, y| {
+            calls += 1;
+            x - y
+        });
+        assert!(matches!(result, Err(CapacityError)));
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn windows_map() {
+        let mut vec = Vec::<i32, 10>::new();
+        let empty: Vec<i32, 5> = vec.windows_map(|&[a, b]| a + b).unwrap();
+        assert!(empty.is_empty());
+
+        vec.push(1).unwrap();
+        let short: Vec<i32, 5> = vec.windows_map(|&[a, b]| a + b).unwrap();
+        assert!(short.is_empty());
+
+        vec.extend_from_slice(&[2, 3, 4]).unwrap();
+        let sums: Vec<i32, 3> = vec.windows_map(|&[a, b]| a + b).unwrap();
+        assert_eq!(sums.as_slice(), [3, 5, 7]);
+
+        let singles: Vec<i32, 4> = vec.windows_map(|&[a]| a * 10).unwrap();
+        assert_eq!(singles.as_slice(), [10, 20, 30, 40]);
+
+        let whole: Vec<i32, 1> = vec.windows_map(|window: &[i32; 4]| window.iter().sum()).unwrap();
+        assert_eq!(whole.as_slice(), [10]);
+
+        let mut calls = 0;
+        let result = vec.windows_map::<2, _, _, 2>(|_| calls += 1);
+        assert!(matches!(result, Err(CapacityError)));
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_map_with_zero_size_window() {
+        let vec = vec![1, 2, 3];
+        let _: Result<Vec<i32, 4>, CapacityError> = vec.windows_map(|&[]| 0);
+    }
+
+    #[test]
+    fn flatten() {
+        let mut nested = Vec::<[i32; 3], 4>::new();
+        let empty: Vec<i32, 1> = Vec::flatten(&nested).unwrap();
+        assert!(empty.is_empty());
+
+        nested.push([1, 2, 3]).unwrap();
+        nested.push([4, 5, 6]).unwrap();
+        let flat: Vec<i32, 6> = Vec::flatten(&nested).unwrap();
+        assert_eq!(flat.as_slice(), [1, 2, 3, 4, 5, 6]);
+
+        assert!(matches!(Vec::flatten::<3, 5>(&nested), Err(CapacityError)));
+
+        let singles = vec![3; [7], [8]];
+        let flat_singles: Vec<i32, 2> = Vec::flatten(&singles).unwrap();
+        assert_eq!(flat_singles.as_slice(), [7, 8]);
+    }
+
+    #[test]
+    fn flatten_should_clone_only_when_result_fits() {
+        let mut nested = Vec::<[Struct; 2], 3>::new();
+        nested.push([Struct { i: 1 }, Struct { i: 2 }]).unwrap();
+        nested.push([Struct { i: 3 }, Struct { i: 4 }]).unwrap();
+
+        assert!(matches!(Vec::flatten::<2, 3>(&nested), Err(CapacityError)));
+        assert_eq!(CLONES.get(), 0);
+
+        let flat: Vec<Struct, 4> = Vec::flatten(&nested).unwrap();
+        assert!(flat.iter().map(|s| s.i).eq([1, 2, 3, 4]));
+        assert_eq!(CLONES.get(), 4);
+        assert_eq!(DROPS.get(), 0);
+    }
+
+    #[test]
+    fn flatten_zero_sized_with_overflowing_length() {
+        let mut nested = Vec::<[(); usize::MAX], 2>::new();
+        nested.push([(); usize::MAX]).unwrap();
+        assert!(matches!(Vec::flatten::<{ usize::MAX }, 3>(&nested), Err(CapacityError)));
+
+        nested.push([(); usize::MAX]).unwrap();
+        assert!(matches!(Vec::flatten::<{ usize::MAX }, 3>(&nested), Err(CapacityError)));
+
+        let mut small = Vec::<[(); 2], 2>::new();
+        small.push([(); 2]).unwrap();
+        small.push([(); 2]).unwrap();
+        let flat: Vec<(), 4> = Vec::flatten(&small).unwrap();
+        assert_eq!(flat.len(), 4);
+    }
+
+    #[test]
+    fn pairs() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.pairs().count(), 0);
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.pairs().count(), 0);
+
+        vec.push(2).unwrap();
+        assert!(vec.pairs().eq([(&1, &2)]));
+
+        vec.extend_from_slice(&[3, 4]).unwrap();
+        assert!(vec.pairs().eq([(&1, &2), (&2, &3), (&3, &4)]));
+    }
+
+    #[test]
+    fn retain_by_key() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.retain_by_key(|n| *n, &[1]);
+        assert!(vec.is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+        vec.retain_by_key(|n| *n, &[]);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6, 7]);
+
+        vec.retain_by_key(|n| *n, &[9, 2, 6, 1]);
+        assert_eq!(vec.as_slice(), [3, 4, 5, 7]);
+
+        vec.retain_by_key(|n| *n % 2 == 0, &[true]);
+        assert_eq!(vec.as_slice(), [3, 5, 7]);
+
+        vec.retain_by_key(|n| *n > 0, &[true]);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn retain_by_key_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.retain_by_key(|s| s.i, &[1, 4, 5]);
+        assert!(vec.iter().map(|s| s.i).eq([2, 3, 6]));
+        assert_eq!(DROPS.get(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn retain_by_key_should_keep_vector_valid_when_key_panics() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            vec.retain_by_key(
+                |s| {
+                    assert!(s.i != 4, "key panic");
+                    s.i
+                },
+                &[2, 6],
+            );
+        }));
+        assert!(result.is_err());
+        assert_eq!(DROPS.get(), 1);
+        assert!(vec.iter().map(|s| s.i).eq([1, 3, 4, 5, 6]));
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+    }
+
+    #[test]
+    fn with_slice() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.with_slice(<[i32]>::len), 0);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.with_slice(|slice| slice.iter().sum::<i32>()), 6);
+        assert!(vec.with_slice(|slice| slice.contains(&2)));
+    }
+
+    #[test]
+    fn with_slice_mut() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.with_slice_mut(|slice| slice.len()), 0);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.with_slice_mut(<[i32]>::reverse);
+        assert_eq!(vec.as_slice(), [3, 2, 1]);
+
+        let last = vec.with_slice_mut(|slice| {
+            slice.fill(7);
+            slice.last().copied()
+        });
+        assert_eq!(last, Some(7));
+        assert_eq!(vec.as_slice(), [7, 7, 7]);
+    }
+
+    #[test]
+    fn count_where() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.count_where(|_| true), 0);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
+        assert_eq!(vec.count_where(|_| true), 7);
+        assert_eq!(vec.count_where(|_| false), 0);
+        assert_eq!(vec.count_where(|n| *n % 2 == 0), 3);
+        assert_eq!(vec.count_where(|n| *n > 5), 2);
+    }
+
+    #[test]
+    fn find_map() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.find_map(|n| Some(*n)), None);
+
+        vec.extend_from_slice(&[1, 3, 4, 5, 6]).unwrap();
+        let mut calls = 0;
+        let first_even_half = vec.find_map(|n| {
+            calls += 1;
+            (n % 2 == 0).then(|| n.div_euclid(2))
+        });
+        assert_eq!(first_even_half, Some(2));
+        assert_eq!(calls, 3);
+
+        assert_eq!(vec.find_map(|n| (*n > 10).then_some(*n)), None);
+    }
+
+    #[test]
+    fn all() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(vec.all(|_| false));
+
+        vec.extend_from_slice(&[2, 4, 5, 6]).unwrap();
+        assert!(vec.all(|n| *n > 0));
+
+        let mut calls = 0;
+        assert!(!vec.all(|n| {
+            calls += 1;
+            n % 2 == 0
+        }));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn any() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert!(!vec.any(|_| true));
+
+        vec.extend_from_slice(&[1, 3, 4, 5]).unwrap();
+        assert!(!vec.any(|n| *n > 5));
+
+        let mut calls = 0;
+        assert!(vec.any(|n| {
+            calls += 1;
+            n % 2 == 0
+        }));
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn is_palindrome() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert!(vec.is_palindrome());
+
+        vec.push(b'a').unwrap();
+        assert!(vec.is_palindrome());
+
+        vec.clear();
+        vec.extend_from_slice(b"racecar").unwrap();
+        assert!(vec.is_palindrome());
+
+        vec.clear();
+        vec.extend_from_slice(b"abba").unwrap();
+        assert!(vec.is_palindrome());
+
+        vec.clear();
+        vec.extend_from_slice(b"abca").unwrap();
+        assert!(!vec.is_palindrome());
+
+        vec.clear();
+        vec.extend_from_slice(b"ab").unwrap();
+        assert!(!vec.is_palindrome());
+    }
+
+    #[test]
+    fn common_prefix_len() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.common_prefix_len(&[]), 0);
+        assert_eq!(vec.common_prefix_len(&[1, 2]), 0);
+
+        vec.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(vec.common_prefix_len(&[]), 0);
+        assert_eq!(vec.common_prefix_len(&[9, 2, 3, 4]), 0);
+        assert_eq!(vec.common_prefix_len(&[1, 2, 9, 4]), 2);
+        assert_eq!(vec.common_prefix_len(&[1, 2]), 2);
+        assert_eq!(vec.common_prefix_len(&[1, 2, 3, 4]), 4);
+        assert_eq!(vec.common_prefix_len(&[1, 2, 3, 4, 5, 6]), 4);
+    }
+
+    #[test]
+    fn max_by_key() {
+        let mut vec = Vec::<(i32, char), 10>::new();
+        assert!(vec.max_by_key(|e| e.0).is_none());
+
+        vec.extend_from_slice(&[(1, 'a'), (3, 'b'), (2, 'c'), (3, 'd'), (0, 'e')]).unwrap();
+        assert_eq!(vec.max_by_key(|e| e.0), Some(&(3, 'd')));
+        assert_eq!(vec.max_by_key(|e| -e.0), Some(&(0, 'e')));
+        assert_eq!(vec.max_by_key(|_| 0), Some(&(0, 'e')));
+    }
+
+    #[test]
+    fn min_by_key() {
+        let mut vec = Vec::<(i32, char), 10>::new();
+        assert!(vec.min_by_key(|e| e.0).is_none());
+
+        vec.extend_from_slice(&[(1, 'a'), (0, 'b'), (2, 'c'), (0, 'd'), (3, 'e')]).unwrap();
+        assert_eq!(vec.min_by_key(|e| e.0), Some(&(0, 'b')));
+        assert_eq!(vec.min_by_key(|e| -e.0), Some(&(3, 'e')));
+        assert_eq!(vec.min_by_key(|_| 0), Some(&(1, 'a')));
+    }
+
+    #[test]
+    fn argmax_and_argmin() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.argmax(), None);
+        assert_eq!(vec.argmin(), None);
+
+        vec.push(5).unwrap();
+        assert_eq!(vec.argmax(), Some(0));
+        assert_eq!(vec.argmin(), Some(0));
+
+        vec.extend_from_slice(&[2, 7, 1, 7, 1, 3]).unwrap();
+        assert_eq!(vec.argmax(), Some(2));
+        assert_eq!(vec.argmin(), Some(3));
+
+        vec.clear();
+        vec.extend_from_slice(&[4, 4, 4]).unwrap();
+        assert_eq!(vec.argmax(), Some(0));
+        assert_eq!(vec.argmin(), Some(0));
+
+        let mut floats = Vec::<f64, 5>::new();
+        floats.extend_from_slice(&[1.0, f64::NAN, 3.0, -1.0]).unwrap();
+        assert_eq!(floats.argmax(), Some(2));
+        assert_eq!(floats.argmin(), Some(3));
+    }
+
+    #[test]
+    fn sum() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.sum::<i32>(), 0);
+
+        vec.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(vec.sum::<i32>(), 10);
+
+        vec.push(-10).unwrap();
+        assert_eq!(vec.sum::<i32>(), 0);
+    }
+
+    #[test]
+    fn product() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.product::<i32>(), 1);
+
+        vec.extend_from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(vec.product::<i32>(), 24);
+
+        vec.push(0).unwrap();
+        assert_eq!(vec.product::<i32>(), 0);
+    }
+
+    #[test]
+    fn wrapping_sum() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.wrapping_sum(), 0);
+        assert_eq!(vec.wrapping_checksum::<u16>(), 0);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.wrapping_sum(), 6);
+        assert_eq!(vec.wrapping_checksum::<u32>(), 6);
+
+        vec.extend_from_slice(&[u8::MAX, u8::MAX]).unwrap();
+        assert_eq!(vec.wrapping_sum(), 4);
+        assert_eq!(vec.wrapping_checksum::<u8>(), 4);
+        assert_eq!(vec.wrapping_checksum::<u16>(), 516);
+
+        let signed = Vec::<i8, 2>::from_slice_copy(&[i8::MAX, 1]).unwrap();
+        assert_eq!(signed.wrapping_sum(), i8::MIN);
+    }
+
+    #[test]
+    fn fold() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.fold(7, |sum, b| sum + b), 7);
+
+        vec.extend_from_slice(&[1, 2, 3, 250]).unwrap();
+        assert_eq!(vec.fold(u8::MIN, |sum, b| sum.wrapping_add(*b)), 0);
+        assert_eq!(vec.fold(u32::MIN, |sum, b| sum * 10 + u32::from(*b)), 1480);
+    }
+
+    #[test]
+    fn try_fold() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.try_fold(u8::MAX, |sum, b| sum.checked_add(*b).ok_or(())), Ok(u8::MAX));
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.try_fold(u8::MIN, |sum, b| sum.checked_add(*b).ok_or(())), Ok(6));
+
+        vec.extend_from_slice(&[250, 1]).unwrap();
+        let mut visited = 0;
+        let result = vec.try_fold(u8::MIN, |sum, b| {
+            visited += 1;
+            sum.checked_add(*b).ok_or(visited)
+        });
+        assert_eq!(result, Err(4));
+        assert_eq!(visited, 4);
+    }
+
+    #[test]
+    fn push_array() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert!(vec.push_array([]).is_ok());
+        assert!(vec.is_empty());
+
+        assert!(vec.push_array([1, 2]).is_ok());
+        assert!(vec.push_array([3, 4]).is_ok());
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+
+        assert!(matches!(vec.push_array([5, 6]), Err(CapacityError)));
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+
+        assert!(vec.push_array([5]).is_ok());
+        assert!(vec.is_full());
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn push_array_should_move_elements() {
+        let mut vec = Vec::<Struct, 3>::new();
+
+        vec.push_array([Struct { i: 1 }, Struct { i: 2 }]).unwrap();
+        assert_eq!(DROPS.get(), 0);
+        assert_eq!(CLONES.get(), 0);
+
+        vec.push_array([Struct { i: 3 }, Struct { i: 4 }]).unwrap_err();
+        assert_eq!(DROPS.get(), 2);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2]));
+    }
+
+    #[test]
+    fn try_swap_remove() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.try_swap_remove(0), None);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(vec.try_swap_remove(5), None);
+        assert_eq!(vec.try_swap_remove(99), None);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        assert_eq!(vec.try_swap_remove(0), Some(1));
+        assert_eq!(vec.as_slice(), [5, 2, 3, 4]);
+
+        assert_eq!(vec.try_swap_remove(3), Some(4));
+        assert_eq!(vec.as_slice(), [5, 2, 3]);
+
+        assert_eq!(vec.try_swap_remove(1), Some(2));
+        assert_eq!(vec.try_swap_remove(1), Some(3));
+        assert_eq!(vec.try_swap_remove(0), Some(5));
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn try_swap_remove_should_not_drop_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        assert!(vec.try_swap_remove(3).is_none());
+        assert_eq!(vec.try_swap_remove(0).unwrap().i, 1);
+        assert_eq!(DROPS.get(), 1);
+        assert!(vec.iter().map(|s| s.i).eq([3, 2]));
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn try_remove() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.try_remove(0), None);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(vec.try_remove(5), None);
+        assert_eq!(vec.try_remove(99), None);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        assert_eq!(vec.try_remove(0), Some(1));
+        assert_eq!(vec.as_slice(), [2, 3, 4, 5]);
+
+        assert_eq!(vec.try_remove(3), Some(5));
+        assert_eq!(vec.as_slice(), [2, 3, 4]);
+
+        assert_eq!(vec.try_remove(1), Some(3));
+        assert_eq!(vec.as_slice(), [2, 4]);
+
+        assert_eq!(vec.try_remove(1), Some(4));
+        assert_eq!(vec.try_remove(0), Some(2));
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn try_remove_should_not_drop_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        assert!(vec.try_remove(3).is_none());
+        assert_eq!(vec.try_remove(0).unwrap().i, 1);
+        assert_eq!(DROPS.get(), 1);
+        assert!(vec.iter().map(|s| s.i).eq([2, 3]));
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn rotate_to_front() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+
+        vec.rotate_to_front(0);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.rotate_to_front(2);
+        assert_eq!(vec.as_slice(), [3, 1, 2, 4, 5]);
+
+        vec.rotate_to_front(4);
+        assert_eq!(vec.as_slice(), [5, 3, 1, 2, 4]);
+
+        vec.rotate_to_front(1);
+        assert_eq!(vec.as_slice(), [3, 5, 1, 2, 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (is 3) should be < len (is 3)")]
+    fn rotate_to_front_with_index_out_of_bounds() {
+        let mut vec = vec![1, 2, 3];
+        vec.rotate_to_front(3);
+    }
+
+    #[test]
+    fn swap_ends() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.swap_ends(0);
+        assert!(vec.is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        vec.swap_ends(0);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.swap_ends(1);
+        assert_eq!(vec.as_slice(), [5, 2, 3, 4, 1]);
+
+        vec.swap_ends(2);
+        assert_eq!(vec.as_slice(), [4, 1, 3, 5, 2]);
+
+        vec.push(6).unwrap();
+        vec.swap_ends(3);
+        assert_eq!(vec.as_slice(), [5, 2, 6, 4, 1, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ends length (is 3) should be <= half of len (is 5)")]
+    fn swap_ends_with_overlapping_ends() {
+        let mut vec = vec![1, 2, 3, 4, 5];
+        vec.swap_ends(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "ends length (is 4) should be <= half of len (is 3)")]
+    fn swap_ends_with_more_elements_than_length() {
+        let mut vec = vec![1, 2, 3];
+        vec.swap_ends(4);
+    }
+
+    #[test]
+    fn remove_range() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.remove_range(..);
+        vec.remove_range(0..0);
+        assert!(vec.is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        vec.remove_range(3..3);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6, 7, 8]);
+
+        vec.remove_range(1..3);
+        assert_eq!(vec.as_slice(), [1, 4, 5, 6, 7, 8]);
+
+        vec.remove_range((Bound::Excluded(0), Bound::Included(1)));
+        assert_eq!(vec.as_slice(), [1, 5, 6, 7, 8]);
+
+        vec.remove_range(..=1);
+        assert_eq!(vec.as_slice(), [6, 7, 8]);
+
+        vec.remove_range(2..);
+        assert_eq!(vec.as_slice(), [6, 7]);
+
+        vec.remove_range(..);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn remove_range_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.remove_range(1..4);
+        assert!(vec.iter().map(|s| s.i).eq([1, 5, 6]));
+        assert_eq!(DROPS.get(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 4 out of range for slice of length 3")]
+    fn remove_range_with_range_out_of_bounds() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.remove_range(1..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "slice index starts at 2 but ends at 1")]
+    fn remove_range_with_start_greater_than_end() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        #[expect(clippy::reversed_empty_ranges, reason = "testing invalid range")]
+        vec.remove_range(2..1);
+    }
+
+    #[test]
+    fn truncate_front() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.truncate_front(0);
+        assert!(vec.is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        vec.truncate_front(5);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.truncate_front(99);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.truncate_front(3);
+        assert_eq!(vec.as_slice(), [3, 4, 5]);
+
+        vec.truncate_front(1);
+        assert_eq!(vec.as_slice(), [5]);
+
+        vec.truncate_front(0);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn truncate_front_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.truncate_front(5);
+        assert_eq!(DROPS.get(), 0);
+
+        vec.truncate_front(2);
+        assert_eq!(DROPS.get(), 3);
+        assert!(vec.iter().map(|s| s.i).eq([4, 5]));
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn shift_left() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.shift_left(0);
+        vec.shift_left(3);
+        assert!(vec.is_empty());
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        vec.shift_left(0);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
+
+        vec.shift_left(1);
+        assert_eq!(vec.as_slice(), [2, 3, 4, 5]);
+
+        vec.shift_left(3);
+        assert_eq!(vec.as_slice(), [5]);
+
+        vec.shift_left(99);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn shift_left_should_drop_removed_elements_once() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.shift_left(0);
+        assert_eq!(DROPS.get(), 0);
+
+        vec.shift_left(2);
+        assert_eq!(DROPS.get(), 2);
+        assert!(vec.iter().map(|s| s.i).eq([3, 4, 5]));
+
+        vec.shift_left(10);
+        assert_eq!(DROPS.get(), 5);
+        assert!(vec.is_empty());
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
+    }
+
+    #[test]
+    fn prepend_from_slice() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert!(vec.prepend_from_slice(&[]).is_ok());
+        assert!(vec.is_empty());
+
+        assert!(vec.prepend_from_slice(&[3, 4]).is_ok());
+        assert_eq!(vec.as_slice(), [3, 4]);
+
+        assert!(vec.prepend_from_slice(&[1, 2]).is_ok());
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+
+        assert!(matches!(vec.prepend_from_slice(&[0, 0]), Err(CapacityError)));
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
+
+        assert!(vec.prepend_from_slice(&[0]).is_ok());
+        assert!(vec.is_full());
+        assert_eq!(vec.as_slice(), [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn prepend_from_slice_should_clone_elements() {
+        let mut vec = Vec::<Struct, 3>::new();
+        vec.push(Struct { i: 3 }).unwrap();
+
+        let src = [Struct { i: 1 }, Struct { i: 2 }];
+        vec.prepend_from_slice(&src).unwrap();
+        assert_eq!(CLONES.get(), 2);
+        assert_eq!(DROPS.get(), 0);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+
+        vec.prepend_from_slice(&src).unwrap_err();
+        assert_eq!(CLONES.get(), 2);
+    }
+
+    #[test]
+    fn retain() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.retain(|_| false);
+        assert!(vec.is_empty());
 
-impl<'a, T> IterMut<'a, T> {
-    /// Creates mutable iterator.
-    #[inline]
-    pub const fn new(data: &'a mut [MaybeUninit<T>], size: usize) -> Self {
-        Self { data, size, index: 0 }
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+        vec.retain(|_| true);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6]);
+
+        let mut visited = Vec::<i32, 10>::new();
+        vec.retain(|n| {
+            visited.push(*n).unwrap();
+            n % 2 == 0
+        });
+        assert_eq!(vec.as_slice(), [2, 4, 6]);
+        assert_eq!(visited.as_slice(), [1, 2, 3, 4, 5, 6]);
+
+        vec.retain(|_| false);
+        assert!(vec.is_empty());
     }
-}
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+    #[test]
+    fn retain_should_drop_removed_elements_in_order() {
+        thread_local! {
+            static DROP_ORDER: RefCell<Vec<i32, 10>> = const {RefCell::new(Vec::new())};
+        }
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index == self.size {
-            None
-        } else {
-            // SAFETY:
-            // - `self.index` is within bounds of `self.data`.
-            // - The element at `self.index` has been initialized.
-            let value = unsafe { &mut *self.data[self.index].as_mut_ptr() };
+        struct Resource(i32);
 
-            self.index += 1;
-            Some(value)
+        impl Drop for Resource {
+            fn drop(&mut self) {
+                DROP_ORDER.with_borrow_mut(|order| order.push(self.0).unwrap());
+            }
         }
-    }
-}
 
-impl<'a, T: 'a, const CAPACITY: usize> IntoIterator for &'a mut Vec<T, CAPACITY> {
-    type IntoIter = IterMut<'a, T>;
-    type Item = &'a mut T;
+        let mut vec = Vec::<Resource, 10>::new();
+        for i in 1..=8 {
+            vec.push(Resource(i)).unwrap();
+        }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.iter_mut()
+        let mut dropped_before_visit = Vec::<usize, 10>::new();
+        vec.retain(|resource| {
+            dropped_before_visit.push(DROP_ORDER.with_borrow(Vec::len)).unwrap();
+            resource.0 % 3 == 0
+        });
+        assert!(vec.iter().map(|resource| resource.0).eq([3, 6]));
+        assert!(DROP_ORDER.with_borrow(|order| order.as_slice() == [1, 2, 4, 5, 7, 8]));
+        assert_eq!(dropped_before_visit.as_slice(), [0, 1, 2, 2, 3, 4, 4, 5]);
+
+        drop(vec);
+        assert!(DROP_ORDER.with_borrow(|order| order.as_slice() == [1, 2, 4, 5, 7, 8, 3, 6]));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn retain_should_keep_vector_valid_when_predicate_panics() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-    extern crate alloc;
-    extern crate std;
-    use alloc::format;
-    use core::cell::Cell;
-    use core::error::Error;
-    use std::thread_local;
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            vec.retain(|s| {
+                assert!(s.i != 4, "predicate panic");
+                s.i % 2 != 0
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(DROPS.get(), 1);
+        assert!(vec.iter().map(|s| s.i).eq([1, 3, 4, 5, 6]));
 
-    fn assert_is_core_error<T: Error>() {}
+        vec.push(Struct { i: 7 }).unwrap();
+        assert_eq!(vec.len(), 6);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 7);
+        assert_eq!(CLONES.get(), 0);
+    }
 
     #[test]
-    fn new() {
-        let mut vec = Vec::<Struct, 10>::new();
+    fn map_in_place() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.map_in_place(|n| n * 2);
         assert!(vec.is_empty());
-        assert!(!vec.is_full());
-        assert_eq!(vec.len(), 0);
-        assert_eq!(vec.capacity(), 10);
-        assert_eq!(vec.as_slice(), []);
-        assert_eq!(vec.as_mut_slice(), []);
-        assert_eq!(DEFAULTS.get(), 0);
+
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.map_in_place(|n| n * 2);
+        assert_eq!(vec.as_slice(), [2, 4, 6]);
     }
 
     #[test]
-    #[should_panic(expected = "CAPACITY must be greater than 0")]
-    fn new_with_capacity_zero() {
-        let _ = Vec::<i32, 0>::new();
+    fn map_in_place_should_move_elements_without_cloning() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.map_in_place(|s| Struct { i: s.i * 10 });
+        assert!(vec.iter().map(|s| s.i).eq([10, 20, 30]));
+        assert_eq!(DROPS.get(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
     }
 
     #[test]
-    fn default() {
-        let mut vec = Vec::<Struct, 10>::default();
-        assert!(vec.is_empty());
-        assert!(!vec.is_full());
-        assert_eq!(vec.len(), 0);
-        assert_eq!(vec.capacity(), 10);
-        assert_eq!(vec.as_slice(), []);
-        assert_eq!(vec.as_mut_slice(), []);
-        assert_eq!(DEFAULTS.get(), 0);
+    fn map_in_place_should_keep_vector_valid_when_f_panics() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            vec.map_in_place(|s| {
+                assert!(s.i != 3, "f panic");
+                Struct { i: s.i * 10 }
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(DROPS.get(), 3);
+        assert!(vec.iter().map(|s| s.i).eq([10, 20, 4, 5]));
+
+        vec.push(Struct { i: 6 }).unwrap();
+        assert_eq!(vec.len(), 5);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 8);
     }
 
     #[test]
-    #[should_panic(expected = "CAPACITY must be greater than 0")]
-    fn default_with_capacity_zero() {
-        let _ = Vec::<i32, 0>::default();
+    fn retain_reporting() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.retain_reporting(|_| true), 10);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!(vec.retain_reporting(|_| true), 4);
+        assert_eq!(vec.retain_reporting(|n| n % 2 == 0), 7);
+        assert_eq!(vec.as_slice(), [2, 4, 6]);
+        assert_eq!(vec.retain_reporting(|_| false), 10);
+        assert!(vec.is_empty());
     }
 
     #[test]
-    fn capacity() {
-        let mut vec = Vec::<i32, 3>::new();
-        assert_eq!(vec.capacity(), 3);
+    fn retain_indexed() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.retain_indexed(|_, _| false);
+        assert!(vec.is_empty());
 
-        vec.set_len(2).unwrap();
-        assert_eq!(vec.capacity(), 3);
+        vec.extend_from_slice(&[10, 11, 12, 13, 14, 15, 16, 17]).unwrap();
+        vec.retain_indexed(|_, _| true);
+        assert_eq!(vec.as_slice(), [10, 11, 12, 13, 14, 15, 16, 17]);
 
-        vec.push(1).unwrap();
-        assert_eq!(vec.capacity(), 3);
+        let mut indexes = Vec::<usize, 10>::new();
+        vec.retain_indexed(|index, value| {
+            indexes.push(index).unwrap();
+            index != 1 && *value != 13
+        });
+        assert_eq!(indexes.as_slice(), [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(vec.as_slice(), [10, 12, 14, 15, 16, 17]);
 
-        vec.clear();
-        assert_eq!(vec.capacity(), 3);
+        vec.retain_indexed(|index, _| index % 2 == 1);
+        assert_eq!(vec.as_slice(), [12, 15, 17]);
+
+        vec.retain_indexed(|_, _| false);
+        assert!(vec.is_empty());
     }
 
     #[test]
-    fn len() {
-        let mut vec = Vec::<i32, 3>::new();
-        assert_eq!(vec.len(), 0);
-
-        vec.set_len(2).unwrap();
-        assert_eq!(vec.len(), 2);
+    fn retain_indexed_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        vec.push(1).unwrap();
-        assert_eq!(vec.len(), 3);
+        vec.retain_indexed(|index, _| index > 3);
+        assert!(vec.iter().map(|s| s.i).eq([5, 6]));
+        assert_eq!(DROPS.get(), 4);
 
-        vec.clear();
-        assert_eq!(vec.len(), 0);
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
     }
 
     #[test]
-    fn is_empty() {
-        let mut vec = Vec::<i32, 3>::new();
-        assert!(vec.is_empty());
+    fn retain_indexed_should_keep_vector_valid_when_predicate_panics() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        vec.push(1).unwrap();
-        assert!(!vec.is_empty());
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            vec.retain_indexed(|index, _| {
+                assert!(index != 3, "predicate panic");
+                index % 2 == 0
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(DROPS.get(), 1);
+        assert!(vec.iter().map(|s| s.i).eq([1, 3, 4, 5, 6]));
 
-        vec.set_len(2).unwrap();
-        assert!(!vec.is_empty());
+        vec.push(Struct { i: 7 }).unwrap();
+        assert_eq!(vec.len(), 6);
 
-        vec.clear();
-        assert!(vec.is_empty());
+        drop(vec);
+        assert_eq!(DROPS.get(), 7);
     }
 
     #[test]
-    fn is_full() {
-        let mut vec = Vec::<i32, 3>::new();
-        assert!(!vec.is_full());
+    fn fill_range() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.fill_range(.., 1);
+        vec.fill_range(0..0, 1);
+        assert!(vec.is_empty());
 
-        vec.push(1).unwrap();
-        assert!(!vec.is_full());
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        vec.fill_range(2..2, 0);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
 
-        vec.set_len(3).unwrap();
-        assert!(vec.is_full());
+        vec.fill_range(1..3, 0);
+        assert_eq!(vec.as_slice(), [1, 0, 0, 4, 5]);
 
-        vec.clear();
-        assert!(!vec.is_full());
+        vec.fill_range(..=1, 7);
+        assert_eq!(vec.as_slice(), [7, 7, 0, 4, 5]);
 
-        vec.push(1).unwrap();
-        vec.push(1).unwrap();
-        vec.push(1).unwrap();
-        assert!(vec.is_full());
+        vec.fill_range(4.., 8);
+        assert_eq!(vec.as_slice(), [7, 7, 0, 4, 8]);
+
+        vec.fill_range(.., 9);
+        assert_eq!(vec.as_slice(), [9, 9, 9, 9, 9]);
     }
 
     #[test]
-    fn push() {
-        let mut vec = Vec::<i32, 2>::new();
-        assert!(vec.push(1).is_ok());
-        assert!(vec.push(2).is_ok());
+    fn fill_range_should_clone_value() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=4 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        assert!(matches!(vec.push(3), Err(CapacityError)));
-        assert_eq!(format!("{}", vec.push(3).unwrap_err()), "vector needs larger capacity");
-        assert_is_core_error::<CapacityError>();
+        vec.fill_range(1..3, Struct { i: 0 });
+        assert!(vec.iter().map(|s| s.i).eq([1, 0, 0, 4]));
+        assert_eq!(DROPS.get(), 2);
+    }
 
-        assert_eq!(vec.as_slice(), &[1, 2]);
-        assert!(vec.get(2).is_none());
-        assert!(vec.get(99).is_none());
+    #[test]
+    #[should_panic(expected = "out of range for slice of length 3")]
+    fn fill_range_with_range_past_length() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.fill_range(1..4, 0);
     }
 
     #[test]
-    fn push_should_not_create_default_elements() {
-        let mut vec = Vec::<Struct, 10>::new();
+    fn replace_all_where() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.replace_all_where(|_| true, 0);
+        assert!(vec.is_empty());
 
-        vec.push(Struct { i: 0 }).unwrap();
+        vec.extend_from_slice(&[3, 120, 7, -40, 101]).unwrap();
+        vec.replace_all_where(|_| false, 0);
+        assert_eq!(vec.as_slice(), [3, 120, 7, -40, 101]);
 
-        assert_eq!(DEFAULTS.get(), 0);
-        assert_eq!(vec.as_slice(), &[Struct { i: 0 }]);
+        vec.replace_all_where(|n| *n < 0, 0);
+        assert_eq!(vec.as_slice(), [3, 120, 7, 0, 101]);
+
+        vec.replace_all_where(|n| *n > 100, 100);
+        assert_eq!(vec.as_slice(), [3, 100, 7, 0, 100]);
+
+        vec.replace_all_where(|_| true, 1);
+        assert_eq!(vec.as_slice(), [1, 1, 1, 1, 1]);
     }
 
     #[test]
-    fn push_should_not_clone_element() {
+    fn replace_all_where_should_move_value_into_last_match() {
         let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        vec.push(Struct { i: 1 }).unwrap();
+        vec.replace_all_where(|s| s.i == 3, Struct { i: 0 });
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 0, 4, 5]));
         assert_eq!(CLONES.get(), 0);
+        assert_eq!(DROPS.get(), 1);
 
-        vec.push(Struct { i: 2 }).unwrap();
-        vec.push(Struct { i: 3 }).unwrap();
-        assert_eq!(CLONES.get(), 0);
+        vec.replace_all_where(|s| s.i == 1 || s.i == 5, Struct { i: 9 });
+        assert!(vec.iter().map(|s| s.i).eq([9, 2, 0, 4, 9]));
+        assert_eq!(CLONES.get(), 1);
+        assert_eq!(DROPS.get(), 3);
 
-        assert_eq!(vec.as_slice(), &[Struct { i: 1 }, Struct { i: 2 }, Struct { i: 3 }]);
+        vec.replace_all_where(|_| false, Struct { i: 7 });
+        assert_eq!(DROPS.get(), 4);
     }
 
     #[test]
-    fn clear() {
-        let mut vec = Vec::<i32, 3>::new();
+    fn iter_mut_from() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.iter_mut_from(0).count(), 0);
+        assert_eq!(vec.iter_mut_from(5).count(), 0);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        assert!(vec.iter_mut_from(0).map(|n| *n).eq([1, 2, 3, 4, 5]));
+        assert!(vec.iter_mut_from(2).map(|n| *n).eq([3, 4, 5]));
+        assert!(vec.iter_mut_from(4).map(|n| *n).eq([5]));
+        assert_eq!(vec.iter_mut_from(5).count(), 0);
+        assert_eq!(vec.iter_mut_from(99).count(), 0);
+        assert_eq!(vec.iter_mut_from(99).next_back(), None);
+
+        for n in vec.iter_mut_from(3) {
+            *n = 0;
+        }
+        assert_eq!(vec.as_slice(), [1, 2, 3, 0, 0]);
+
+        let mut iter = vec.iter_mut_from(1);
+        assert_eq!(iter.next_back(), Some(&mut 0));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), Some(&mut 0));
+        assert_eq!(iter.next(), None);
+    }
 
-        vec.extend_from_slice(&[1, 2, 3]).unwrap();
-        assert_eq!(vec.len(), 3);
-        assert_eq!(vec.capacity(), 3);
-        assert!(!vec.is_empty());
-        assert!(vec.is_full());
-        assert_eq!(vec.as_slice(), &[1, 2, 3]);
+    #[test]
+    fn swap_insert() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert!(vec.swap_insert(0, 1).is_ok());
+        assert_eq!(vec.as_slice(), [1]);
 
-        vec.clear();
+        assert!(vec.swap_insert(1, 2).is_ok());
+        assert_eq!(vec.as_slice(), [1, 2]);
 
-        assert_eq!(vec.len(), 0);
-        assert_eq!(vec.capacity(), 3);
-        assert!(vec.is_empty());
-        assert!(!vec.is_full());
-        assert_eq!(vec.as_slice(), &[]);
+        assert!(vec.swap_insert(0, 3).is_ok());
+        assert_eq!(vec.as_slice(), [3, 2, 1]);
+
+        assert!(vec.swap_insert(1, 4).is_ok());
+        assert_eq!(vec.as_slice(), [3, 4, 1, 2]);
+
+        assert!(vec.swap_insert(4, 5).is_ok());
+        assert_eq!(vec.as_slice(), [3, 4, 1, 2, 5]);
+
+        assert!(matches!(vec.swap_insert(0, 6), Err(CapacityError)));
+        assert_eq!(vec.as_slice(), [3, 4, 1, 2, 5]);
     }
 
     #[test]
-    fn clear_should_drop_all_allocated_elements() {
-        let mut vec = Vec::<Struct, 10>::new();
-        assert_eq!(DROPS.get(), 0);
-
-        let s = Struct { i: 0 };
-        for _ in 1..=3 {
-            vec.push(s.clone()).unwrap();
-        }
+    fn swap_insert_should_not_drop_elements() {
+        let mut vec = Vec::<Struct, 2>::new();
+        vec.swap_insert(0, Struct { i: 1 }).unwrap();
+        vec.swap_insert(0, Struct { i: 2 }).unwrap();
         assert_eq!(DROPS.get(), 0);
+        assert!(vec.iter().map(|s| s.i).eq([2, 1]));
 
-        vec.clear();
-        assert_eq!(DROPS.get(), 3);
-
-        assert_eq!(CLONES.get(), 3); // the three clones before push
-        assert_eq!(DEFAULTS.get(), 0);
+        vec.swap_insert(0, Struct { i: 3 }).unwrap_err();
+        assert_eq!(DROPS.get(), 1);
+        assert_eq!(CLONES.get(), 0);
     }
 
     #[test]
-    fn set_len() {
-        let mut vec = Vec::<i32, 3>::new();
-
-        // New length less than capacity
-        assert!(vec.set_len(1).is_ok());
-        assert_eq!(vec.len(), 1);
-        assert!(!vec.is_empty());
-        assert!(!vec.is_full());
-        assert_eq!(vec.as_slice(), [0]);
+    #[should_panic(expected = "insertion index (is 2) should be <= len (is 1)")]
+    fn swap_insert_with_index_out_of_bounds() {
+        let mut vec = Vec::<i32, 5>::new();
+        vec.push(1).unwrap();
+        let _ = vec.swap_insert(2, 2);
+    }
 
-        // New length larger than capacity
-        assert!(matches!(vec.set_len(100), Err(CapacityError)));
-        assert_eq!(format!("{}", vec.set_len(100).unwrap_err()), "vector needs larger capacity");
-        assert_is_core_error::<CapacityError>();
-        assert_eq!(vec.len(), 1);
-        assert!(!vec.is_empty());
-        assert!(!vec.is_full());
-        assert_eq!(vec.as_slice(), [0]);
+    #[test]
+    fn drain_sorted() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.drain_sorted().next(), None);
+        assert!(vec.is_empty());
 
-        // New length equal to capacity
-        vec.clear();
-        vec.set_len(vec.capacity()).unwrap();
-        assert_eq!(vec.len(), 3);
-        assert!(!vec.is_empty());
-        assert!(vec.is_full());
-        assert_eq!(vec.as_slice(), [0, 0, 0]);
+        vec.extend_from_slice(&[3, 1, 4, 1, 5, 9, 2, 6]).unwrap();
+        assert!(vec.drain_sorted().eq([1, 1, 2, 3, 4, 5, 6, 9]));
+        assert!(vec.is_empty());
 
-        // New length zero
-        assert!(vec.set_len(0).is_ok());
-        assert_eq!(vec.len(), 0);
+        vec.extend_from_slice(&[3, 1, 2]).unwrap();
+        let mut drain = vec.drain_sorted();
+        assert_eq!(drain.size_hint(), (3, Some(3)));
+        assert_eq!(drain.next(), Some(1));
+        assert_eq!(drain.size_hint(), (2, Some(2)));
+        drop(drain);
         assert!(vec.is_empty());
-        assert!(!vec.is_full());
-        assert_eq!(vec.as_slice(), []);
+
+        vec.push(1).unwrap();
+        assert_eq!(vec.as_slice(), [1]);
     }
 
     #[test]
-    fn set_len_should_create_default_elements() {
+    fn drain_sorted_should_drop_remaining_elements() {
         let mut vec = Vec::<Struct, 10>::new();
+        for i in [4, 2, 5, 1, 3] {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        // Length zero, no defaults
-        vec.set_len(0).unwrap();
-        assert_eq!(DEFAULTS.get(), 0);
+        let mut drain = vec.drain_sorted();
+        assert_eq!(drain.next().unwrap().i, 1);
+        assert_eq!(drain.next().unwrap().i, 2);
+        assert_eq!(DROPS.get(), 2);
 
-        // Length error, no defaults
-        vec.set_len(99).unwrap_err();
-        assert_eq!(DEFAULTS.get(), 0);
+        drop(drain);
+        assert_eq!(DROPS.get(), 5);
+        assert!(vec.is_empty());
 
-        // Maximum length, create `CAPACITY` default values
-        vec.set_len(10).unwrap();
-        assert_eq!(DEFAULTS.get(), 10);
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
+    }
 
-        // Smaller length than current, no defaults
-        DEFAULTS.set(0);
-        vec.set_len(5).unwrap();
-        assert_eq!(DEFAULTS.get(), 0);
+    #[derive(Debug)]
+    enum TryFromFnError {
+        Capacity,
+        Generator,
+    }
 
-        // Larger length than current, create `current length - new length` default values
-        DEFAULTS.set(0);
-        vec.set_len(8).unwrap();
-        assert_eq!(DEFAULTS.get(), 3);
+    impl From<CapacityError> for TryFromFnError {
+        fn from(_: CapacityError) -> Self {
+            Self::Capacity
+        }
     }
 
     #[test]
-    fn set_len_should_drop_all_allocated_elements() {
-        let mut vec = Vec::<Struct, 10>::new();
-        assert_eq!(DROPS.get(), 0);
+    fn try_from_fn() {
+        let empty = Vec::<usize, 5>::try_from_fn(0, |_| Err(TryFromFnError::Generator)).unwrap();
+        assert!(empty.is_empty());
 
-        let s = Struct { i: 0 };
-        for _ in 1..=5 {
-            vec.push(s.clone()).unwrap();
-        }
-        assert_eq!(DROPS.get(), 0);
+        let vec = Vec::<usize, 5>::try_from_fn(3, |index| Ok::<_, CapacityError>(index * 2));
+        assert_eq!(vec.unwrap().as_slice(), [0, 2, 4]);
 
-        // Same length, no drops
-        vec.set_len(5).unwrap();
-        assert_eq!(DROPS.get(), 0);
+        let full = Vec::<usize, 5>::try_from_fn(5, Ok::<_, CapacityError>).unwrap();
+        assert_eq!(full.as_slice(), [0, 1, 2, 3, 4]);
 
-        // Length error, no drop
-        vec.set_len(999).unwrap_err();
-        assert_eq!(DROPS.get(), 0);
+        let too_long = Vec::<usize, 5>::try_from_fn(6, Ok::<_, TryFromFnError>);
+        assert!(matches!(too_long, Err(TryFromFnError::Capacity)));
 
-        // Length smaller, drop elements after
-        vec.set_len(2).unwrap();
+        let failed = Vec::<usize, 5>::try_from_fn(4, |index| {
+            if index == 2 { Err(TryFromFnError::Generator) } else { Ok(index) }
+        });
+        assert!(matches!(failed, Err(TryFromFnError::Generator)));
+    }
+
+    #[test]
+    fn try_from_fn_should_drop_created_elements_on_error() {
+        let mut calls = 0;
+        let result = Vec::<Struct, 5>::try_from_fn(5, |index| {
+            calls += 1;
+            if index == 3 {
+                Err(TryFromFnError::Generator)
+            } else {
+                Ok(Struct { i: i32::try_from(index).unwrap() })
+            }
+        });
+
+        assert!(matches!(result, Err(TryFromFnError::Generator)));
+        assert_eq!(calls, 4);
         assert_eq!(DROPS.get(), 3);
+        assert_eq!(CLONES.get(), 0);
 
-        // Same length again, no change in number of drops
-        vec.set_len(2).unwrap();
+        let too_long =
+            Vec::<Struct, 5>::try_from_fn(6, |_| Ok::<_, CapacityError>(Struct { i: 0 }));
+        assert!(too_long.is_err());
         assert_eq!(DROPS.get(), 3);
+    }
 
-        // Length zero, drop all
-        DROPS.set(0);
-        vec.set_len(0).unwrap();
-        assert_eq!(DROPS.get(), 2);
+    #[test]
+    fn from_iter_truncating() {
+        let empty = Vec::<i32, 3>::from_iter_truncating([]);
+        assert!(empty.is_empty());
 
-        assert_eq!(CLONES.get(), 5); // the five clones before push
-        assert_eq!(DEFAULTS.get(), 0);
+        let partial = Vec::<i32, 3>::from_iter_truncating([1, 2]);
+        assert_eq!(partial.as_slice(), [1, 2]);
+
+        let mut pulled = 0;
+        let full = Vec::<i32, 3>::from_iter_truncating((1..).inspect(|_| pulled += 1));
+        assert_eq!(full.as_slice(), [1, 2, 3]);
+        assert_eq!(pulled, 3);
     }
 
     #[test]
-    fn first() {
-        let mut vec = Vec::<i32, 4>::new();
-        assert!(vec.first().is_none());
+    fn from_iter_truncating_should_drop_discarded_elements() {
+        let vec = Vec::<Struct, 2>::from_iter_truncating([
+            Struct { i: 1 },
+            Struct { i: 2 },
+            Struct { i: 3 },
+        ]);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2]));
+        assert_eq!(DROPS.get(), 1);
 
-        vec.push(1).unwrap();
-        assert_eq!(vec.first().unwrap(), &1);
+        drop(vec);
+        assert_eq!(DROPS.get(), 3);
+    }
 
-        vec.push(2).unwrap();
-        vec.push(3).unwrap();
-        assert_eq!(vec.first(), Some(&1));
+    #[test]
+    fn collect_until_full() {
+        let (empty, mut empty_rest) = Vec::<i32, 3>::collect_until_full([]);
+        assert!(empty.is_empty());
+        assert!(empty_rest.next().is_none());
+
+        let (partial, mut partial_rest) = Vec::<i32, 3>::collect_until_full([1, 2]);
+        assert_eq!(partial.as_slice(), [1, 2]);
+        assert!(partial_rest.next().is_none());
+
+        let mut pulled = 0;
+        let (full, rest) = Vec::<i32, 3>::collect_until_full((1..=7).inspect(|_| pulled += 1));
+        assert_eq!(full.as_slice(), [1, 2, 3]);
+        assert!(rest.eq(4..=7));
+        assert_eq!(pulled, 7);
+
+        let mut iter = [1, 2, 3, 4, 5].into_iter();
+        let (batch, _) = Vec::<i32, 2>::collect_until_full(iter.by_ref());
+        assert_eq!(batch.as_slice(), [1, 2]);
+        assert_eq!(iter.next(), Some(3));
     }
 
     #[test]
-    fn first_mut() {
+    fn collect_until_full_should_not_consume_remaining_elements() {
+        let (vec, rest) = Vec::<Struct, 2>::collect_until_full([
+            Struct { i: 1 },
+            Struct { i: 2 },
+            Struct { i: 3 },
+        ]);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2]));
+        assert_eq!(DROPS.get(), 0);
+
+        assert!(rest.map(|s| s.i).eq([3]));
+        assert_eq!(DROPS.get(), 1);
+    }
+
+    #[test]
+    fn push_iter_saturating() {
         let mut vec = Vec::<i32, 4>::new();
-        assert!(vec.first_mut().is_none());
+        assert!(vec.push_iter_saturating([]).next().is_none());
+        assert!(vec.is_empty());
 
-        vec.push(1).unwrap();
-        assert_eq!(vec.first_mut().unwrap(), &1);
+        let mut partial_rest = vec.push_iter_saturating([1, 2]);
+        assert!(partial_rest.next().is_none());
+        assert_eq!(vec.as_slice(), [1, 2]);
 
-        vec.push(2).unwrap();
-        vec.push(3).unwrap();
-        assert_eq!(vec.first_mut().unwrap(), &1);
+        let mut pulled = 0;
+        let rest = vec.push_iter_saturating((3..=6).inspect(|_| pulled += 1));
+        assert!(rest.eq(5..=6));
+        assert_eq!(pulled, 4);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
 
-        *vec.first_mut().unwrap() = 4;
-        assert_eq!(vec.first_mut(), Some(&mut 4));
-        assert_eq!(vec.as_slice(), [4, 2, 3]);
+        let mut iter = [7, 8].into_iter();
+        let _ = vec.push_iter_saturating(iter.by_ref());
+        assert_eq!(iter.next(), Some(7));
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
     }
 
     #[test]
-    fn last() {
-        let mut vec = Vec::<i32, 2>::new();
-        assert!(vec.last().is_none());
-
-        vec.push(1).unwrap();
-        assert_eq!(vec.last().unwrap(), &1);
+    fn push_iter_saturating_should_not_consume_remaining_elements() {
+        let mut vec = Vec::<Struct, 3>::new();
+        vec.push(Struct { i: 1 }).unwrap();
 
-        vec.push(2).unwrap();
-        assert_eq!(vec.last().unwrap(), &2);
+        let rest = vec.push_iter_saturating([Struct { i: 2 }, Struct { i: 3 }, Struct { i: 4 }]);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3]));
+        assert_eq!(DROPS.get(), 0);
 
-        vec.push(3).unwrap_err();
-        assert_eq!(vec.last(), Some(&2));
+        assert!(rest.map(|s| s.i).eq([4]));
+        assert_eq!(DROPS.get(), 1);
     }
 
     #[test]
-    fn last_mut() {
-        let mut vec = Vec::<i32, 2>::new();
-        assert!(vec.last_mut().is_none());
-
-        vec.push(1).unwrap();
-        assert_eq!(vec.last_mut().unwrap(), &1);
+    fn truncate_while() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.truncate_while(|_| true);
+        assert!(vec.is_empty());
 
-        vec.push(2).unwrap();
-        assert_eq!(vec.last_mut().unwrap(), &2);
+        vec.extend_from_slice(&[0, 1, 0, 2, 0, 0]).unwrap();
+        vec.truncate_while(|_| false);
+        assert_eq!(vec.as_slice(), [0, 1, 0, 2, 0, 0]);
 
-        vec.push(3).unwrap_err();
-        assert_eq!(vec.last_mut().unwrap(), &2);
+        let mut calls = 0;
+        vec.truncate_while(|n| {
+            calls += 1;
+            *n == 0
+        });
+        assert_eq!(vec.as_slice(), [0, 1, 0, 2]);
+        assert_eq!(calls, 3);
 
-        *vec.last_mut().unwrap() = 4;
-        assert_eq!(vec.as_slice(), [1, 4]);
+        vec.truncate_while(|n| *n != 1);
+        assert_eq!(vec.as_slice(), [0, 1]);
 
-        vec.set_len(1).unwrap();
-        assert_eq!(vec.last_mut(), Some(&mut 1));
-        assert_eq!(vec.as_slice(), [1]);
+        vec.truncate_while(|_| true);
+        assert!(vec.is_empty());
     }
 
     #[test]
-    fn get() {
-        let mut vec = Vec::<i32, 2>::new();
-        assert!(vec.get(0).is_none());
-
-        vec.push(1).unwrap();
-        assert_eq!(vec.get(0), Some(&1));
+    fn truncate_while_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        vec.push(2).unwrap();
-        assert_eq!(vec.get(1), Some(&2));
+        vec.truncate_while(|s| s.i > 2);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2]));
+        assert_eq!(DROPS.get(), 3);
 
-        assert_eq!(vec.get(2), None);
-        assert_eq!(vec.get(3), None);
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
     }
 
     #[test]
-    fn get_mut() {
-        let mut vec = Vec::<i32, 2>::new();
-        assert!(vec.get_mut(0).is_none());
+    fn truncate_to_slice() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.truncate_to_slice(&[1, 2]);
+        assert!(vec.is_empty());
 
-        vec.push(1).unwrap();
-        assert_eq!(vec.get_mut(0), Some(&mut 1));
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        vec.truncate_to_slice(&[1, 2, 3, 4, 5, 6]);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
 
-        vec.push(2).unwrap();
-        *vec.get_mut(1).unwrap() = 3;
-        assert_eq!(vec.get_mut(1), Some(&mut 3));
+        vec.truncate_to_slice(&[1, 2, 3, 4, 5]);
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5]);
 
-        assert_eq!(vec.get_mut(2), None);
-        assert_eq!(vec.get_mut(3), None);
+        vec.truncate_to_slice(&[1, 2, 3, 0, 5]);
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
+
+        vec.truncate_to_slice(&[1, 2]);
+        assert_eq!(vec.as_slice(), [1, 2]);
+
+        vec.truncate_to_slice(&[0, 2]);
+        assert!(vec.is_empty());
     }
 
     #[test]
-    fn pop() {
-        let mut vec = Vec::<Struct, 4>::new();
-        assert!(vec.pop().is_none());
+    fn truncate_to_slice_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        let s1 = Struct { i: 1 };
-        vec.push(s1).unwrap();
+        vec.truncate_to_slice(&[Struct { i: 1 }, Struct { i: 2 }, Struct { i: 0 }]);
+        assert!(vec.iter().map(|s| s.i).eq([1, 2]));
+        assert_eq!(DROPS.get(), 6); // 3 removed elements, 3 elements of the slice
 
-        let s2 = Struct { i: 2 };
-        vec.push(s2).unwrap();
+        drop(vec);
+        assert_eq!(DROPS.get(), 8);
+    }
 
-        let s3 = Struct { i: 3 };
-        vec.push(s3).unwrap();
+    #[test]
+    fn drain_front_while() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.drain_front_while(|_| true);
+        assert!(vec.is_empty());
 
-        assert_eq!(vec.pop().unwrap().i, 3);
-        assert_eq!(vec.len(), 2);
-        assert_eq!(DROPS.get(), 1);
+        vec.extend_from_slice(&[0, 0, 1, 0, 2, 0]).unwrap();
+        vec.drain_front_while(|_| false);
+        assert_eq!(vec.as_slice(), [0, 0, 1, 0, 2, 0]);
 
-        assert_eq!(vec.pop().unwrap().i, 2);
-        assert_eq!(vec.pop().unwrap().i, 1);
+        let mut calls = 0;
+        vec.drain_front_while(|n| {
+            calls += 1;
+            *n == 0
+        });
+        assert_eq!(vec.as_slice(), [1, 0, 2, 0]);
+        assert_eq!(calls, 3);
+
+        vec.drain_front_while(|n| *n != 2);
+        assert_eq!(vec.as_slice(), [2, 0]);
+
+        vec.drain_front_while(|_| true);
         assert!(vec.is_empty());
-        assert!(vec.pop().is_none());
+    }
+
+    #[test]
+    fn drain_front_while_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=5 {
+            vec.push(Struct { i }).unwrap();
+        }
+
+        vec.drain_front_while(|s| s.i < 4);
+        assert!(vec.iter().map(|s| s.i).eq([4, 5]));
         assert_eq!(DROPS.get(), 3);
 
-        assert_eq!(DEFAULTS.get(), 0);
-        assert_eq!(CLONES.get(), 0); // from the three pushes
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
     }
 
-    fn not<F>(f: F) -> impl Fn(&Struct) -> bool
-    where
-        F: Fn(&Struct) -> bool,
-    {
-        move |s| !f(s)
+    #[test]
+    fn as_chunks() {
+        let empty = Vec::<u8, 10>::new();
+        let (empty_chunks, empty_remainder) = empty.as_chunks::<4>();
+        assert!(empty_chunks.is_empty());
+        assert!(empty_remainder.is_empty());
+
+        let mut vec = Vec::<u8, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+
+        let (chunks, remainder) = vec.as_chunks::<4>();
+        assert_eq!(chunks, [[1, 2, 3, 4], [5, 6, 7, 8]]);
+        assert_eq!(remainder, [9]);
+
+        let (front, back) = vec.as_rchunks::<4>();
+        assert_eq!(front, [1]);
+        assert_eq!(back, [[2, 3, 4, 5], [6, 7, 8, 9]]);
+
+        let (exact, none) = vec.as_chunks::<3>();
+        assert_eq!(exact, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        assert!(none.is_empty());
+
+        let (short, all) = vec.as_rchunks::<10>();
+        assert!(all.is_empty());
+        assert_eq!(short, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
     }
 
     #[test]
-    fn pop_if() {
-        let is_even = |s: &Struct| s.i % 2 == 0;
+    fn as_chunks_mut() {
+        let mut vec = Vec::<u8, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6, 7]).unwrap();
 
-        let mut vec = Vec::<Struct, 4>::new();
-        assert!(vec.pop_if(is_even).is_none());
+        let (chunks, remainder) = vec.as_chunks_mut::<2>();
+        for chunk in chunks.iter_mut() {
+            chunk.swap(0, 1);
+        }
+        remainder[0] = 0;
+        assert_eq!(vec.as_slice(), [2, 1, 4, 3, 6, 5, 0]);
 
-        let s1 = Struct { i: 1 };
-        vec.push(s1).unwrap();
+        let (front, back) = vec.as_rchunks_mut::<3>();
+        front[0] = 9;
+        back[1] = [7, 7, 7];
+        assert_eq!(vec.as_slice(), [9, 1, 4, 3, 7, 7, 7]);
+    }
 
-        let s2 = Struct { i: 2 };
-        vec.push(s2).unwrap();
+    #[test]
+    fn chunks_mut_array() {
+        let mut vec = Vec::<u8, 10>::new();
+        for length in 0..=10 {
+            vec.set_len(length).unwrap();
 
-        let s3 = Struct { i: 3 };
-        vec.push(s3).unwrap();
+            let (chunks, remainder) = vec.chunks_mut_array::<4>();
+            assert_eq!(chunks.len(), length.div_euclid(4));
+            assert_eq!(remainder.len(), length % 4);
 
-        assert!(vec.pop_if(is_even).is_none());
-        assert_eq!(vec.len(), 3);
-        assert_eq!(DROPS.get(), 0);
+            for (index, chunk) in chunks.iter_mut().enumerate() {
+                *chunk = [u8::try_from(index).unwrap(); 4];
+            }
+            remainder.fill(9);
+        }
+        assert_eq!(vec.as_slice(), [0, 0, 0, 0, 1, 1, 1, 1, 9, 9]);
+    }
 
-        assert_eq!(vec.pop_if(not(is_even)).unwrap().i, 3);
-        assert_eq!(vec.len(), 2);
-        assert_eq!(DROPS.get(), 1);
+    #[test]
+    fn split_first_chunk() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert_eq!(vec.split_first_chunk::<0>(), Some((&[], &[][..])));
+        assert!(vec.split_first_chunk::<1>().is_none());
+        assert!(vec.split_first_chunk_mut::<1>().is_none());
 
-        assert!(vec.pop_if(not(is_even)).is_none());
-        assert_eq!(vec.len(), 2);
-        assert_eq!(DROPS.get(), 1);
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.split_first_chunk::<2>(), Some((&[1, 2], &[3][..])));
+        assert_eq!(vec.split_first_chunk::<3>(), Some((&[1, 2, 3], &[][..])));
+        assert!(vec.split_first_chunk::<4>().is_none());
+
+        let (head, tail) = vec.split_first_chunk_mut::<1>().unwrap();
+        head[0] = 10;
+        tail.fill(0);
+        assert_eq!(vec.as_slice(), [10, 0, 0]);
+    }
 
-        assert_eq!(vec.pop_if(is_even).unwrap().i, 2);
-        assert_eq!(vec.len(), 1);
-        assert_eq!(DROPS.get(), 2);
+    #[test]
+    fn split_last_chunk() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert_eq!(vec.split_last_chunk::<0>(), Some((&[][..], &[])));
+        assert!(vec.split_last_chunk::<1>().is_none());
+        assert!(vec.split_last_chunk_mut::<1>().is_none());
 
-        assert_eq!(vec.pop_if(not(is_even)).unwrap().i, 1);
-        assert!(vec.is_empty());
-        assert_eq!(DROPS.get(), 3);
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.split_last_chunk::<2>(), Some((&[1][..], &[2, 3])));
+        assert_eq!(vec.split_last_chunk::<3>(), Some((&[][..], &[1, 2, 3])));
+        assert!(vec.split_last_chunk::<4>().is_none());
+
+        let (init, last) = vec.split_last_chunk_mut::<1>().unwrap();
+        init.fill(0);
+        last[0] = 30;
+        assert_eq!(vec.as_slice(), [0, 0, 30]);
+    }
 
-        assert!(vec.pop_if(is_even).is_none());
-        assert!(vec.is_empty());
-        assert_eq!(DROPS.get(), 3);
+    #[test]
+    fn last_chunk() {
+        let mut vec = Vec::<i32, 5>::new();
+        assert_eq!(vec.last_chunk::<0>(), Some(&[]));
+        assert!(vec.last_chunk::<1>().is_none());
+        assert!(vec.last_chunk_mut::<1>().is_none());
 
-        assert_eq!(DEFAULTS.get(), 0);
-        assert_eq!(CLONES.get(), 0); // from the three pushes
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        assert_eq!(vec.last_chunk::<2>(), Some(&[2, 3]));
+        assert_eq!(vec.last_chunk::<3>(), Some(&[1, 2, 3]));
+        assert!(vec.last_chunk::<4>().is_none());
+
+        *vec.last_chunk_mut::<2>().unwrap() = [20, 30];
+        assert_eq!(vec.as_slice(), [1, 20, 30]);
     }
 
     #[test]
-    fn iter() {
+    fn head_and_tail() {
         let mut vec = Vec::<i32, 10>::new();
-        for i in 1..=7 {
-            vec.push(i).unwrap();
-        }
+        assert_eq!(vec.head(0), []);
+        assert_eq!(vec.head(3), []);
+        assert_eq!(vec.tail(3), []);
+        assert_eq!(vec.head_mut(3), []);
+        assert_eq!(vec.tail_mut(3), []);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5]).unwrap();
+        assert_eq!(vec.head(0), []);
+        assert_eq!(vec.tail(0), []);
+        assert_eq!(vec.head(2), [1, 2]);
+        assert_eq!(vec.tail(2), [4, 5]);
+        assert_eq!(vec.head(5), [1, 2, 3, 4, 5]);
+        assert_eq!(vec.tail(5), [1, 2, 3, 4, 5]);
+        assert_eq!(vec.head(usize::MAX), [1, 2, 3, 4, 5]);
+        assert_eq!(vec.tail(usize::MAX), [1, 2, 3, 4, 5]);
+
+        vec.head_mut(1).fill(0);
+        vec.tail_mut(2).fill(9);
+        assert_eq!(vec.as_slice(), [0, 2, 3, 9, 9]);
+
+        vec.tail_mut(10).fill(7);
+        assert_eq!(vec.as_slice(), [7, 7, 7, 7, 7]);
+    }
 
-        let even_sum = vec.iter().filter(|v| *v % 2 == 0).sum::<i32>();
-        assert_eq!(even_sum, 12);
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn as_chunks_with_zero_size() {
+        let vec = Vec::<u8, 10>::new();
+        let _ = vec.as_chunks::<0>();
+    }
+
+    #[test]
+    fn iter_step_by() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.iter_step_by(2).len(), 0);
+
+        vec.extend_from_slice(&[0, 1, 2, 3, 4, 5, 6, 7]).unwrap();
+        assert!(vec.iter_step_by(1).eq(vec.iter()));
+
+        let mut evens = vec.iter_step_by(2);
+        assert_eq!(evens.size_hint(), (4, Some(4)));
+        assert_eq!(evens.next(), Some(&0));
+        assert_eq!(evens.size_hint(), (3, Some(3)));
+        assert!(evens.eq([&2, &4, &6]));
+
+        assert!(vec.iter_step_by(3).eq([&0, &3, &6]));
+        assert!(vec.iter_step_by(8).eq([&0]));
+        assert!(vec.iter_step_by(100).eq([&0]));
+    }
 
-        assert_eq!(vec.iter().count(), 7);
+    #[test]
+    #[should_panic(expected = "assertion failed: step != 0")]
+    fn iter_step_by_with_zero_step() {
+        let vec = Vec::<i32, 10>::new();
+        let _ = vec.iter_step_by(0);
     }
 
     #[test]
-    fn into_iter() {
-        let mut vec = Vec::<i32, 10>::new();
-        for i in 1..=7 {
-            vec.push(i).unwrap();
+    fn clone_from_slice() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
         }
+        let src = [Struct { i: 4 }, Struct { i: 5 }, Struct { i: 6 }];
 
-        let mut s = 0;
-        for i in &vec {
-            s += i;
-        }
-        assert_eq!(s, 28);
+        vec.clone_from_slice(&src);
+        assert!(vec.iter().map(|s| s.i).eq([4, 5, 6]));
+        assert_eq!(CLONES.get(), 3);
+        assert_eq!(DROPS.get(), 3);
+        assert_eq!(vec.len(), 3);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
     }
 
     #[test]
-    fn iter_mut() {
+    #[should_panic(expected = "source slice length (is 2) should be == len (is 3)")]
+    fn clone_from_slice_with_different_length() {
         let mut vec = Vec::<i32, 10>::new();
-        for i in 1..=7 {
-            vec.push(i).unwrap();
-        }
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.clone_from_slice(&[1, 2]);
+    }
 
-        let even_sum = vec.iter_mut().filter(|v| **v % 2 == 0).map(|v| *v).sum::<i32>();
-        assert_eq!(even_sum, 12);
+    #[test]
+    fn clone_into() {
+        let mut src = Vec::<i32, 5>::new();
+        let mut dst = Vec::<i32, 5>::new();
+        src.clone_into(&mut dst);
+        assert!(dst.is_empty());
 
-        assert_eq!(vec.iter().count(), 7);
+        src.extend_from_slice(&[1, 2, 3]).unwrap();
+        src.clone_into(&mut dst);
+        assert_eq!(dst.as_slice(), [1, 2, 3]);
+
+        dst.extend_from_slice(&[4, 5]).unwrap();
+        src.clone_into(&mut dst);
+        assert_eq!(dst.as_slice(), [1, 2, 3]);
+
+        src.clear();
+        src.clone_into(&mut dst);
+        assert!(dst.is_empty());
     }
 
     #[test]
-    fn into_iter_mut() {
-        let mut vec = Vec::<i32, 10>::new();
-        for i in 1..=7 {
-            vec.push(i).unwrap();
+    fn clone_into_should_drop_old_elements_first() {
+        let mut src = Vec::<Struct, 5>::new();
+        src.push(Struct { i: 1 }).unwrap();
+        src.push(Struct { i: 2 }).unwrap();
+
+        let mut dst = Vec::<Struct, 5>::new();
+        for i in 3..=5 {
+            dst.push(Struct { i }).unwrap();
         }
 
-        let mut s = 0;
-        for i in &mut vec {
-            *i *= 2;
-            s += *i;
-        }
-        assert_eq!(s, 56);
+        src.clone_into(&mut dst);
+        assert!(dst.iter().map(|s| s.i).eq([1, 2]));
+        assert_eq!(DROPS.get(), 3);
+        assert_eq!(CLONES.get(), 2);
+
+        dst.clone_from(&src);
+        assert!(dst.iter().map(|s| s.i).eq([1, 2]));
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 4);
     }
 
     #[test]
-    fn as_slice() {
-        let mut vec = Vec::<i32, 1000>::new();
-        assert_eq!(vec.as_slice(), []);
+    fn remove_all() {
+        let mut vec = Vec::<u8, 10>::new();
+        assert_eq!(vec.remove_all(&0), 0);
 
-        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.extend_from_slice(&[0, 1, 0, 0, 2, 0, 3]).unwrap();
+        assert_eq!(vec.remove_all(&9), 0);
+        assert_eq!(vec.as_slice(), [0, 1, 0, 0, 2, 0, 3]);
+
+        assert_eq!(vec.remove_all(&0), 4);
         assert_eq!(vec.as_slice(), [1, 2, 3]);
+
+        assert_eq!(vec.remove_all(&2), 1);
+        assert_eq!(vec.as_slice(), [1, 3]);
+
+        vec.extend_from_slice(&[1, 1]).unwrap();
+        assert_eq!(vec.remove_all(&1), 3);
+        assert_eq!(vec.as_slice(), [3]);
     }
 
     #[test]
-    fn as_mut_slice() {
-        let mut vec = Vec::<i32, 1000>::new();
-        assert_eq!(vec.as_mut_slice(), []);
+    fn remove_all_should_drop_removed_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in [1, 2, 1, 3, 1] {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        vec.extend_from_slice(&[1, 2, 3]).unwrap();
-        assert_eq!(vec.as_mut_slice(), [1, 2, 3]);
+        let value = Struct { i: 1 };
+        assert_eq!(vec.remove_all(&value), 3);
+        assert!(vec.iter().map(|s| s.i).eq([2, 3]));
+        assert_eq!(DROPS.get(), 3);
 
-        vec.set_len(1000).unwrap();
-        vec.as_mut_slice().fill(2);
-        assert_eq!(vec.as_slice().iter().sum::<i32>(), 2000);
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
+        assert_eq!(CLONES.get(), 0);
     }
 
     #[test]
-    fn extend_from_slice_with_empty_vector_and_empty_slice() {
-        let src = [];
-        let mut dst = Vec::<i32, 3>::new();
-        let result = dst.extend_from_slice(&src);
+    fn retain_mut_until() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.retain_mut_until(|_, _| ControlFlow::Continue(false));
+        assert!(vec.is_empty());
 
-        assert!(result.is_ok());
-        assert!(dst.is_empty());
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+        vec.retain_mut_until(|_, _| ControlFlow::Continue(true));
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6]);
+
+        let mut visited = 0;
+        vec.retain_mut_until(|_, _| {
+            visited += 1;
+            ControlFlow::Break(())
+        });
+        assert_eq!(vec.as_slice(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(visited, 1);
+
+        let mut indexes = Vec::<usize, 10>::new();
+        vec.retain_mut_until(|index, n| {
+            indexes.push(index).unwrap();
+            if index == 3 {
+                return ControlFlow::Break(());
+            }
+            *n *= 10;
+            ControlFlow::Continue(index != 1)
+        });
+        assert_eq!(vec.as_slice(), [10, 30, 4, 5, 6]);
+        assert_eq!(indexes.as_slice(), [0, 1, 2, 3]);
+
+        vec.retain_mut_until(|_, _| ControlFlow::Continue(false));
+        assert!(vec.is_empty());
     }
 
     #[test]
-    fn extend_from_slice_with_empty_vector_and_non_empty_slice_within_capacity() {
-        let src = [1, 2];
-        let mut dst = Vec::<i32, 3>::new();
-        let result = dst.extend_from_slice(&src);
+    fn retain_mut_until_should_drop_removed_elements_only() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        assert!(result.is_ok());
-        assert_eq!(dst.len(), 2);
-        assert_eq!(dst.as_slice(), [1, 2]);
+        vec.retain_mut_until(|_, s| {
+            if s.i == 4 { ControlFlow::Break(()) } else { ControlFlow::Continue(s.i != 2) }
+        });
+        assert!(vec.iter().map(|s| s.i).eq([1, 3, 4, 5, 6]));
+        assert_eq!(DROPS.get(), 1);
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
     }
 
     #[test]
-    fn extend_from_slice_with_non_empty_vector_and_empty_slice() {
-        let src = [];
-        let mut dst = Vec::<i32, 3>::new();
-        dst.push(1).unwrap();
-        dst.push(2).unwrap();
-        let result = dst.extend_from_slice(&src);
-
-        assert!(result.is_ok());
-        assert_eq!(dst.len(), 2);
-        assert_eq!(dst.as_slice(), [1, 2]);
+    fn drain_where() {
+        let mut vec = Vec::<i32, 10>::new();
+        assert_eq!(vec.drain_where(|_| true).next(), None);
+
+        vec.extend_from_slice(&[1, 2, 3, 4, 5, 6]).unwrap();
+        let mut drain = vec.drain_where(|n| {
+            *n *= 10;
+            *n % 20 == 0
+        });
+        assert_eq!(drain.size_hint(), (0, Some(6)));
+        assert_eq!(drain.next(), Some(20));
+        assert_eq!(drain.size_hint(), (0, Some(4)));
+        assert!(drain.eq([40, 60]));
+        assert_eq!(vec.as_slice(), [10, 30, 50]);
+
+        assert_eq!(vec.drain_where(|_| false).count(), 0);
+        assert_eq!(vec.as_slice(), [10, 30, 50]);
+
+        assert!(vec.drain_where(|_| true).eq([10, 30, 50]));
+        assert!(vec.is_empty());
     }
 
     #[test]
-    fn extend_from_slice_with_non_empty_vector_and_slice_fits_exactly_into_capacity() {
-        let src = [3, 4, 5];
-        let mut dst = Vec::<i32, 5>::new();
-        dst.push(1).unwrap();
-        dst.push(2).unwrap();
-        let result = dst.extend_from_slice(&src);
+    fn drain_where_should_finish_compacting_and_drop_removed_elements_when_dropped() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        assert!(result.is_ok());
-        assert_eq!(dst.len(), 5);
-        assert!(dst.is_full());
-        assert_eq!(dst.as_slice(), [1, 2, 3, 4, 5]);
-    }
+        let mut visited = 0;
+        let mut drain = vec.drain_where(|s| {
+            visited += 1;
+            s.i % 2 == 0
+        });
+        assert_eq!(drain.next().map(|s| s.i), Some(2));
+        drop(drain);
 
-    #[test]
-    fn extend_from_slice_with_non_empty_vector_and_slice_exceeds_capacity() {
-        let src = [3, 4, 5, 6];
-        let mut dst = Vec::<i32, 5>::new();
-        dst.push(1).unwrap();
-        dst.push(2).unwrap();
-        let result = dst.extend_from_slice(&src);
+        assert_eq!(visited, 6);
+        assert!(vec.iter().map(|s| s.i).eq([1, 3, 5]));
+        assert_eq!(DROPS.get(), 3);
 
-        assert!(result.is_err());
-        assert_eq!(dst.len(), 2);
-        assert_eq!(dst.as_slice(), [1, 2]);
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
+        assert_eq!(CLONES.get(), 0);
     }
 
     #[test]
-    fn extend_from_slice_with_vector_full_and_non_empty_slice() {
-        let src = [3, 4, 5, 6];
-        let mut dst = Vec::<i32, 2>::new();
-        dst.push(1).unwrap();
-        dst.push(2).unwrap();
-        let result = dst.extend_from_slice(&src);
+    fn drain_where_should_keep_vector_valid_when_predicate_panics() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=6 {
+            vec.push(Struct { i }).unwrap();
+        }
 
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            vec.drain_where(|s| {
+                assert!(s.i != 5, "predicate panic");
+                s.i % 2 == 0
+            })
+            .for_each(drop);
+        }));
         assert!(result.is_err());
-        assert_eq!(dst.len(), 2);
-        assert!(dst.is_full());
-        assert_eq!(dst.as_slice(), [1, 2]);
+        assert_eq!(DROPS.get(), 2);
+        assert!(vec.iter().map(|s| s.i).eq([1, 3, 5, 6]));
+
+        drop(vec);
+        assert_eq!(DROPS.get(), 6);
     }
 
     #[test]
-    fn extend_from_slice_with_non_empty_vector_and_non_empty_slice() {
-        let src = [3];
-        let mut dst = Vec::<i32, 5>::new();
-        dst.push(1).unwrap();
-        dst.push(2).unwrap();
-        let result = dst.extend_from_slice(&src);
+    fn extend_from_within() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_within(..).unwrap();
+        assert!(vec.is_empty());
 
-        assert!(result.is_ok());
-        assert_eq!(dst.len(), 3);
-        assert!(!dst.is_full());
-        assert_eq!(dst.as_slice(), [1, 2, 3]);
-    }
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.extend_from_within(1..1).unwrap();
+        assert_eq!(vec.as_slice(), [1, 2, 3]);
 
-    #[test]
-    fn append_with_enough_room() {
-        let mut vec = Vec::<i32, 5>::new();
-        vec.push(1).unwrap();
-        vec.push(2).unwrap();
+        vec.extend_from_within(..).unwrap();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 1, 2, 3]);
 
-        let mut other = Vec::<i32, 20>::new();
-        other.push(3).unwrap();
-        other.push(4).unwrap();
+        vec.extend_from_within(4..=5).unwrap();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 1, 2, 3, 2, 3]);
 
-        let result = vec.append(&mut other);
+        assert!(matches!(vec.extend_from_within(5..), Err(CapacityError)));
+        assert_eq!(vec.as_slice(), [1, 2, 3, 1, 2, 3, 2, 3]);
 
-        assert!(result.is_ok());
-        assert_eq!(vec.len(), 4);
-        assert_eq!(vec.as_slice(), [1, 2, 3, 4]);
-        assert!(other.is_empty());
-        assert_eq!(other.as_slice(), []);
+        vec.extend_from_within(6..).unwrap();
+        assert!(vec.is_full());
+        assert_eq!(vec.as_slice(), [1, 2, 3, 1, 2, 3, 2, 3, 2, 3]);
     }
 
     #[test]
-    fn append_with_not_enough_room() {
-        let mut vec = Vec::<i32, 2>::new();
-        vec.push(1).unwrap();
-        vec.push(2).unwrap();
-
-        let mut other = Vec::<i32, 20>::new();
-        other.push(3).unwrap();
-        other.push(4).unwrap();
+    fn extend_from_within_should_clone_elements() {
+        let mut vec = Vec::<Struct, 10>::new();
+        for i in 1..=3 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        let result = vec.append(&mut other);
+        vec.extend_from_within(1..).unwrap();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 3, 2, 3]));
+        assert_eq!(CLONES.get(), 2);
+        assert_eq!(DROPS.get(), 0);
 
-        assert!(result.is_err());
-        assert_eq!(vec.len(), 2);
-        assert_eq!(vec.as_slice(), [1, 2]);
-        assert_eq!(other.len(), 2);
-        assert_eq!(other.as_slice(), [3, 4]);
+        drop(vec);
+        assert_eq!(DROPS.get(), 5);
     }
 
     #[test]
-    fn clone() {
-        let mut vec = Vec::<i32, 5>::new();
-        let elements = [1, 2, 3];
-        vec.extend_from_slice(&elements).unwrap();
-
-        let new = vec.clone();
-        assert_eq!(new.len(), 3);
-        assert_eq!(new.as_slice(), elements);
+    #[should_panic(expected = "range end index 4 out of range for slice of length 3")]
+    fn extend_from_within_with_range_out_of_bounds() {
+        let mut vec = Vec::<i32, 10>::new();
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        let _ = vec.extend_from_within(1..4);
     }
 
     #[test]
-    fn eq() {
-        let mut a = Vec::<i32, 5>::new();
-        a.extend_from_slice(&[1, 2, 3]).unwrap();
+    fn repeat_fill() {
+        let mut vec = Vec::<i32, 7>::new();
+        vec.repeat_fill();
+        assert!(vec.is_empty());
 
-        let mut b = Vec::<i32, 10>::new();
-        assert_ne!(a, b);
+        vec.push(1).unwrap();
+        vec.repeat_fill();
+        assert_eq!(vec.as_slice(), [1; 7]);
 
-        b.extend_from_slice(&[1, 2, 3]).unwrap();
-        assert_eq!(a, b);
+        vec.repeat_fill();
+        assert_eq!(vec.as_slice(), [1; 7]);
 
-        b.clear();
-        b.extend_from_slice(&[9, 9, 9]).unwrap();
-        assert_ne!(a, b);
+        vec.clear();
+        vec.extend_from_slice(&[1, 2, 3]).unwrap();
+        vec.repeat_fill();
+        assert_eq!(vec.as_slice(), [1, 2, 3, 1, 2, 3, 1]);
+
+        let mut exact = Vec::<i32, 6>::new();
+        exact.extend_from_slice(&[1, 2]).unwrap();
+        exact.repeat_fill();
+        assert_eq!(exact.as_slice(), [1, 2, 1, 2, 1, 2]);
     }
 
     #[test]
-    fn cmp() {
-        let mut a = Vec::<i32, 5>::new();
-        a.extend_from_slice(&[1, 2, 3]).unwrap();
-
-        let mut b = Vec::<i32, 10>::new();
-        assert!(a >= b);
-
-        b.extend_from_slice(&[1, 2, 3]).unwrap();
-        assert!(a >= b);
-        assert!(a <= b);
+    fn repeat_fill_should_clone_elements() {
+        let mut vec = Vec::<Struct, 5>::new();
+        for i in 1..=2 {
+            vec.push(Struct { i }).unwrap();
+        }
 
-        b.clear();
-        b.extend_from_slice(&[9, 9]).unwrap();
-        assert!(a < b);
+        vec.repeat_fill();
+        assert!(vec.iter().map(|s| s.i).eq([1, 2, 1, 2, 1]));
+        assert_eq!(CLONES.get(), 3);
+        assert_eq!(DROPS.get(), 0);
+    }
 
-        b.clear();
-        b.extend_from_slice(&[1, 2]).unwrap();
-        assert!(a > b);
+    #[test]
+    fn zero_sized_type() {
+        let mut vec = Vec::<(), 3>::new();
+        assert_eq!(vec.as_slice().len(), 0);
+
+        for length in 1..=3 {
+            vec.push(()).unwrap();
+            assert_eq!(vec.len(), length);
+            assert_eq!(vec.as_slice().len(), length);
+            assert_eq!(vec.as_mut_slice().len(), length);
+        }
+        assert!(vec.is_full());
+        assert!(vec.push(()).is_err());
+        assert_eq!(vec.iter().count(), 3);
+        assert_eq!(vec.iter_mut().count(), 3);
+        assert_eq!(vec.iter().rev().count(), 3);
+
+        for length in (0..3).rev() {
+            assert_eq!(vec.pop(), Some(()));
+            assert_eq!(vec.len(), length);
+        }
+        assert!(vec.pop().is_none());
+        assert!(vec.as_slice().is_empty());
     }
 
     #[test]
-    fn going_out_of_scope_should_drop_all_allocated_elements() {
-        let s = Struct { i: 0 };
+    fn zero_sized_type_should_drop_elements() {
+        thread_local! {
+            static ZST_DROPS: Cell<usize> = const {Cell::new(0)};
+        }
 
-        {
-            let mut vec = Vec::<Struct, 10>::new();
-            assert_eq!(DROPS.get(), 0);
+        struct Zst;
 
-            for _ in 1..=3 {
-                vec.push(s.clone()).unwrap();
+        impl Drop for Zst {
+            fn drop(&mut self) {
+                ZST_DROPS.set(ZST_DROPS.get() + 1);
             }
-            assert_eq!(DROPS.get(), 0);
-        };
+        }
 
-        assert_eq!(DROPS.get(), 3);
-        assert_eq!(CLONES.get(), 3); // the three clones before push
+        let mut vec = Vec::<Zst, 5>::new();
+        for _ in 0..5 {
+            vec.push(Zst).unwrap();
+        }
+        assert!(vec.push(Zst).is_err());
+        assert_eq!(ZST_DROPS.get(), 1);
+
+        drop(vec.pop());
+        assert_eq!(ZST_DROPS.get(), 2);
+
+        vec.clear();
+        assert_eq!(ZST_DROPS.get(), 6);
+
+        vec.push(Zst).unwrap();
+        vec.push(Zst).unwrap();
+        let mut iter = vec.into_iter();
+        drop(iter.next());
+        assert_eq!(ZST_DROPS.get(), 7);
+        drop(iter);
+        assert_eq!(ZST_DROPS.get(), 8);
     }
 
     #[derive(Debug)]
@@ -1603,4 +8852,18 @@ mod tests {
             self.i == other.i
         }
     }
+
+    impl Eq for Struct {}
+
+    impl PartialOrd for Struct {
+        fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Struct {
+        fn cmp(&self, other: &Self) -> cmp::Ordering {
+            self.i.cmp(&other.i)
+        }
+    }
 }
@@ -0,0 +1,30 @@
+use std::collections::HashSet;
+use std::format;
+
+use static_vector::{Vec, vec};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Packet {
+    id: u32,
+    payload: Vec<u8, 8>,
+}
+
+#[cfg(test)]
+#[test]
+fn derive_on_struct_with_vec_field() {
+    let packet = Packet { id: 1, payload: vec![8; 1, 2, 3] };
+
+    let copy = packet.clone();
+    assert_eq!(packet, copy);
+    assert_ne!(packet, Packet { id: 1, payload: vec![8; 1, 2] });
+
+    let mut set = HashSet::new();
+    assert!(set.insert(packet));
+    assert!(!set.insert(copy));
+    assert_eq!(set.len(), 1);
+
+    assert_eq!(
+        format!("{:?}", Packet { id: 2, payload: vec![8; 4, 5] }),
+        "Packet { id: 2, payload: [4, 5] }"
+    );
+}